@@ -30,11 +30,16 @@ import (
 func NewTequilApiClient(ctx *cli.Context) (*tequilapi_client.Client, error) {
 	address := TequilAPIAddress(ctx)
 	port := TequilAPIPort(ctx)
-	client := tequilapi_client.NewClient(address, port)
+	socket := TequilAPISocket(ctx)
+	client := tequilapi_client.NewNodeClient(address, port, socket)
 
 	_, err := client.Healthcheck()
 	if err != nil {
-		Error(fmt.Sprintf("failed to connect to node via url: %s:%d", address, port))
+		if socket != "" {
+			Error(fmt.Sprintf("failed to connect to node via socket: %s", socket))
+		} else {
+			Error(fmt.Sprintf("failed to connect to node via url: %s:%d", address, port))
+		}
 		return nil, err
 	}
 	return client, nil
@@ -61,3 +66,14 @@ func TequilAPIPort(ctx *cli.Context) int {
 
 	return flag.Value
 }
+
+// TequilAPISocket - wil resolve tequilapi unix socket path from flag if one is provided
+func TequilAPISocket(ctx *cli.Context) string {
+	flag := config.FlagTequilapiSocket
+
+	if ctx.IsSet(flag.Name) {
+		return ctx.String(flag.Name)
+	}
+
+	return flag.Value
+}
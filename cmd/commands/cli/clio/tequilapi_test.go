@@ -0,0 +1,67 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package clio
+
+import (
+	"flag"
+	"net"
+	"net/http"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/urfave/cli/v2"
+
+	"github.com/mysteriumnetwork/node/config"
+)
+
+func TestNewTequilApiClient_UsesUnixSocket(t *testing.T) {
+	socket := filepath.Join(t.TempDir(), "tequilapi.sock")
+	listener, err := net.Listen("unix", socket)
+	require.NoError(t, err)
+
+	server := &http.Server{Handler: http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/healthcheck" {
+			w.WriteHeader(http.StatusNotFound)
+			return
+		}
+		w.Header().Set("Content-Type", "application/json")
+		w.Write([]byte(`{"version": "1.2.3"}`))
+	})}
+	go server.Serve(listener)
+	defer server.Close()
+
+	flags := flag.NewFlagSet("", flag.ContinueOnError)
+	flags.String(config.FlagTequilapiSocket.Name, "", "")
+	require.NoError(t, flags.Set(config.FlagTequilapiSocket.Name, socket))
+	ctx := cli.NewContext(nil, flags, nil)
+
+	client, err := NewTequilApiClient(ctx)
+	require.NoError(t, err)
+
+	healthcheck, err := client.Healthcheck()
+	assert.NoError(t, err)
+	assert.Equal(t, "1.2.3", healthcheck.Version)
+}
+
+func TestTequilAPISocket_DefaultsToTCP(t *testing.T) {
+	ctx := cli.NewContext(nil, flag.NewFlagSet("", flag.ContinueOnError), nil)
+
+	assert.Equal(t, "", TequilAPISocket(ctx))
+}
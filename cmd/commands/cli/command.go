@@ -18,6 +18,8 @@
 package cli
 
 import (
+	"bytes"
+	"encoding/json"
 	"errors"
 	"flag"
 	"fmt"
@@ -58,6 +60,7 @@ const serviceHelp = `service <action> [args]
 	status	<ServiceID>
 	list
 	sessions
+	kill-session	<SessionID>
 
 	example: service start 0x7d5ee3557775aed0b85d691b036769c17349db23 openvpn --openvpn.port=1194 --openvpn.proto=UDP`
 
@@ -66,7 +69,7 @@ func NewCommand() *cli.Command {
 	return &cli.Command{
 		Name:  CommandName,
 		Usage: "Starts a CLI client with a Tequilapi",
-		Flags: []cli.Flag{&config.FlagAgreedTermsConditions, &config.FlagTequilapiAddress, &config.FlagTequilapiPort},
+		Flags: []cli.Flag{&config.FlagAgreedTermsConditions, &config.FlagTequilapiAddress, &config.FlagTequilapiPort, &config.FlagTequilapiSocket},
 		Action: func(ctx *cli.Context) error {
 			client, err := clio.NewTequilApiClient(ctx)
 			if err != nil {
@@ -237,6 +240,7 @@ func (c *cliApp) handleActions(args []string) error {
 		{"help", c.help},
 		{"status", c.status},
 		{"healthcheck", c.healthcheck},
+		{"state", c.state},
 		{"nat", c.nodeMonitoringStatus},
 		{"location", c.location},
 		{"disconnect", c.disconnect},
@@ -534,6 +538,21 @@ func (c *cliApp) location() (err error) {
 	return nil
 }
 
+// state prints daemon state snapshot, suitable for attaching to bug reports
+func (c *cliApp) state() (err error) {
+	state, err := c.tequilapi.State()
+	if err != nil {
+		return err
+	}
+
+	var out bytes.Buffer
+	if err := json.Indent(&out, state, "", "  "); err != nil {
+		return err
+	}
+	fmt.Println(out.String())
+	return nil
+}
+
 func (c *cliApp) help() (err error) {
 	clio.Info("Mysterium CLI commands:")
 	fmt.Println(c.completer.Tree("  "))
@@ -634,6 +653,7 @@ func newAutocompleter(tequilapi *tequilapi_client.Client, proposals []contract.P
 			readline.PcItem("list"),
 			readline.PcItem("status"),
 			readline.PcItem("sessions"),
+			readline.PcItem("kill-session"),
 		),
 		readline.PcItem(
 			"identities",
@@ -667,6 +687,7 @@ func newAutocompleter(tequilapi *tequilapi_client.Client, proposals []contract.P
 			readline.PcItem("gateways"),
 		),
 		readline.PcItem("healthcheck"),
+		readline.PcItem("state"),
 		readline.PcItem("nat"),
 		readline.PcItem("proposals"),
 		readline.PcItem("location"),
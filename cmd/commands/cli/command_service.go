@@ -56,6 +56,12 @@ func (c *cliApp) service(args []string) (err error) {
 		return c.serviceList()
 	case "sessions":
 		return c.serviceSessions()
+	case "kill-session":
+		if len(args) < 2 {
+			fmt.Println(serviceHelp)
+			return errWrongArgumentCount
+		}
+		return c.serviceSessionKill(args[1])
 	default:
 		fmt.Println(serviceHelp)
 		return errUnknownSubCommand(args[0])
@@ -127,6 +133,15 @@ func (c *cliApp) serviceSessions() (err error) {
 	return nil
 }
 
+func (c *cliApp) serviceSessionKill(id string) (err error) {
+	if err := c.tequilapi.SessionKill(id); err != nil {
+		return fmt.Errorf("failed to kill session: %w", err)
+	}
+
+	clio.Status("Terminating", "ID: "+id)
+	return nil
+}
+
 func (c *cliApp) serviceGet(id string) (err error) {
 	service, err := c.tequilapi.Service(id)
 	if err != nil {
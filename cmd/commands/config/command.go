@@ -39,7 +39,7 @@ func NewCommand() *cli.Command {
 		Name:        CommandName,
 		Usage:       "Manage your node config",
 		Description: "Using config subcommands you can view and manage your current node config",
-		Flags:       []cli.Flag{&config.FlagTequilapiAddress, &config.FlagTequilapiPort},
+		Flags:       []cli.Flag{&config.FlagTequilapiAddress, &config.FlagTequilapiPort, &config.FlagTequilapiSocket},
 		Before: func(ctx *cli.Context) error {
 			var err error
 			cmd.tc, err = clio.NewTequilApiClient(ctx)
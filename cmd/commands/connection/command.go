@@ -88,7 +88,7 @@ func NewCommand() *cli.Command {
 		Name:        CommandName,
 		Usage:       "Manage your connection",
 		Description: "Using the connection subcommands you can manage your connection or get additional information about it",
-		Flags:       []cli.Flag{&config.FlagTequilapiAddress, &config.FlagTequilapiPort},
+		Flags:       []cli.Flag{&config.FlagTequilapiAddress, &config.FlagTequilapiPort, &config.FlagTequilapiSocket},
 		Before: func(ctx *cli.Context) error {
 			tc, err := clio.NewTequilApiClient(ctx)
 			if err != nil {
@@ -345,6 +345,7 @@ func (c *command) info(ctx *cli.Context) {
 		inf.set(infDuration, fmt.Sprint(time.Duration(statistics.Duration)*time.Second))
 		inf.set(infTransferred, fmt.Sprintf("%s/%s", datasize.FromBytes(statistics.BytesReceived), datasize.FromBytes(statistics.BytesSent)))
 		inf.set(infThroughput, fmt.Sprintf("%s/%s", datasize.BitSpeed(statistics.ThroughputReceived), datasize.BitSpeed(statistics.ThroughputSent)))
+		inf.set(infLatency, fmt.Sprint(time.Duration(statistics.Latency)*time.Millisecond))
 		inf.set(infSpent, money.New(statistics.TokensSpent).String())
 	}
 
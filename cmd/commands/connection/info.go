@@ -37,6 +37,7 @@ const (
 	infDuration    infoKey = "duration"
 	infTransferred infoKey = "transferred"
 	infThroughput  infoKey = "throughput"
+	infLatency     infoKey = "latency"
 	infSpent       infoKey = "spent"
 	infIdentity    infoKey = "identity"
 )
@@ -63,6 +64,7 @@ func (i *connInfo) printAll() {
 	i.printSingle("Duration:", infDuration)
 	i.printSingle("Transferred:", infTransferred)
 	i.printSingle("Throughput:", infThroughput)
+	i.printSingle("Latency:", infLatency)
 	i.printSingle("Spent:", infSpent)
 }
 
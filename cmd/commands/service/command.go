@@ -78,7 +78,7 @@ func NewCommand(licenseCommandName string) *cli.Command {
 			cmd.RegisterSignalCallback(func() { quit <- nil })
 
 			cmdService := &serviceCommand{
-				tequilapi:    client.NewClient(nodeOptions.TequilapiAddress, nodeOptions.TequilapiPort),
+				tequilapi:    client.NewNodeClient(nodeOptions.TequilapiAddress, nodeOptions.TequilapiPort, nodeOptions.TequilapiSocket),
 				errorChannel: quit,
 			}
 			go func() {
@@ -19,11 +19,13 @@ package cmd
 
 import (
 	"fmt"
+	"math/big"
 	"net"
 	"net/http"
 	"net/url"
 	"path/filepath"
 	"reflect"
+	"strings"
 	"time"
 
 	"github.com/ethereum/go-ethereum/accounts/keystore"
@@ -55,6 +57,8 @@ import (
 	"github.com/mysteriumnetwork/node/core/storage/boltdb"
 	"github.com/mysteriumnetwork/node/core/storage/boltdb/migrations/history"
 	"github.com/mysteriumnetwork/node/core/storage/boltdb/migrator"
+	"github.com/mysteriumnetwork/node/core/webhook"
+	"github.com/mysteriumnetwork/node/datasize"
 	"github.com/mysteriumnetwork/node/dns"
 	"github.com/mysteriumnetwork/node/eventbus"
 	"github.com/mysteriumnetwork/node/feedback"
@@ -88,6 +92,7 @@ import (
 	"github.com/mysteriumnetwork/node/utils/netutil"
 	paymentClient "github.com/mysteriumnetwork/payments/client"
 	psort "github.com/mysteriumnetwork/payments/client/sort"
+	"github.com/mysteriumnetwork/payments/crypto"
 	"github.com/mysteriumnetwork/payments/observer"
 )
 
@@ -137,6 +142,8 @@ type Dependencies struct {
 
 	QualityClient *quality.MysteriumMORQA
 
+	WebhookNotifier *webhook.Notifier
+
 	IPResolver       ip.Resolver
 	LocationResolver *location.Cache
 
@@ -291,6 +298,10 @@ func (di *Dependencies) Bootstrap(nodeOptions node.Options) error {
 		return err
 	}
 
+	if err := di.bootstrapWebhooks(); err != nil {
+		return err
+	}
+
 	if err := di.bootstrapNodeComponents(nodeOptions, tequilaListener); err != nil {
 		return err
 	}
@@ -364,6 +375,14 @@ func (di *Dependencies) createTequilaListener(nodeOptions node.Options) (net.Lis
 		return tequilapi.NewNoopListener()
 	}
 
+	if nodeOptions.TequilapiSocket != "" {
+		tequilaListener, err := tequilapi.NewUnixSocketListener(nodeOptions.TequilapiSocket)
+		if err != nil {
+			return nil, errors.Wrap(err, fmt.Sprintf("could not listen on socket %s", nodeOptions.TequilapiSocket))
+		}
+		return tequilaListener, nil
+	}
+
 	tequilaListener, err := net.Listen("tcp", fmt.Sprintf("%s:%d", nodeOptions.TequilapiAddress, nodeOptions.TequilapiPort))
 	if err != nil {
 		return nil, errors.Wrap(err, fmt.Sprintf("the port %v seems to be taken. Either you're already running a node or it is already used by another application", nodeOptions.TequilapiPort))
@@ -471,6 +490,10 @@ func (di *Dependencies) Shutdown() (err error) {
 		di.BrokerConnection.Close()
 	}
 
+	if di.WebhookNotifier != nil {
+		di.WebhookNotifier.Stop()
+	}
+
 	if di.QualityClient != nil {
 		di.QualityClient.Stop()
 	}
@@ -509,7 +532,7 @@ func (di *Dependencies) bootstrapStorage(path string) error {
 	di.ProviderInvoiceStorage = pingpong.NewProviderInvoiceStorage(invoiceStorage)
 	di.ConsumerTotalsStorage = pingpong.NewConsumerTotalsStorage(di.EventBus)
 	di.HermesPromiseStorage = pingpong.NewHermesPromiseStorage(di.Storage)
-	di.SessionStorage = consumer_session.NewSessionStorage(di.Storage)
+	di.SessionStorage = consumer_session.NewSessionStorage(di.Storage, config.GetInt(config.FlagSessionHistoryLimit))
 	di.SettlementHistoryStorage = pingpong.NewSettlementHistoryStorage(di.Storage)
 	return di.SessionStorage.Subscribe(di.EventBus)
 }
@@ -580,6 +603,13 @@ func (di *Dependencies) bootstrapNodeComponents(nodeOptions node.Options, tequil
 
 	di.bootstrapBeneficiarySaver(nodeOptions)
 
+	connectionConfig := connection.DefaultConfig()
+	dataCap, err := datasize.Parse(config.GetString(config.FlagDataCap))
+	if err != nil {
+		return fmt.Errorf("could not parse data cap: %w", err)
+	}
+	connectionConfig.DataCap = dataCap
+
 	di.ConnectionRegistry = connection.NewRegistry()
 	di.MultiConnectionManager = connection.NewMultiConnectionManager(func() connection.Manager {
 		return connection.NewManager(
@@ -595,7 +625,7 @@ func (di *Dependencies) bootstrapNodeComponents(nodeOptions node.Options, tequil
 			di.EventBus,
 			di.IPResolver,
 			di.LocationResolver,
-			connection.DefaultConfig(),
+			connectionConfig,
 			config.GetDuration(config.FlagStatsReportInterval),
 			connection.NewValidator(
 				di.ConsumerBalanceTracker,
@@ -928,6 +958,39 @@ func (di *Dependencies) bootstrapQualityComponents(options node.OptionsQuality)
 	return nil
 }
 
+func (di *Dependencies) bootstrapWebhooks() error {
+	var urls []string
+	for _, webhookURL := range strings.Split(config.GetString(config.FlagWebhookURLs), ",") {
+		if webhookURL = strings.TrimSpace(webhookURL); webhookURL != "" {
+			urls = append(urls, webhookURL)
+		}
+	}
+	if len(urls) == 0 {
+		return nil
+	}
+
+	if err := di.AllowURLAccess(urls...); err != nil {
+		return errors.Wrap(err, "failed to add firewall exception")
+	}
+
+	var balanceLow *big.Int
+	if threshold := config.GetFloat64(config.FlagWebhookBalanceLow); threshold > 0 {
+		balanceLow = crypto.FloatToBigMyst(threshold)
+	}
+
+	notifier, err := webhook.NewNotifier(urls, config.GetString(config.FlagWebhookSecret), balanceLow, di.HTTPClient)
+	if err != nil {
+		return err
+	}
+	if err := notifier.Subscribe(di.EventBus); err != nil {
+		return err
+	}
+
+	notifier.Start()
+	di.WebhookNotifier = notifier
+	return nil
+}
+
 func (di *Dependencies) bootstrapLocationComponents(options node.Options) (err error) {
 	if err = di.AllowURLAccess(options.Location.IPDetectorURL); err != nil {
 		return errors.Wrap(err, "failed to add firewall exception")
@@ -412,6 +412,7 @@ func (di *Dependencies) registerWireguardConnection(nodeOptions node.Options, re
 		opts := wireguard_connection.Options{
 			DNSScriptDir:     nodeOptions.Directories.Script,
 			HandshakeTimeout: 1 * time.Minute,
+			MTU:              config.GetInt(config.FlagWireguardMTU),
 		}
 		return wireguard_connection.NewConnection(opts, di.IPResolver, endpointFactory, handshakeWaiter)
 	}
@@ -436,6 +437,7 @@ func (di *Dependencies) registerScrapingConnection(nodeOptions node.Options, res
 		opts := wireguard_connection.Options{
 			DNSScriptDir:     nodeOptions.Directories.Script,
 			HandshakeTimeout: 1 * time.Minute,
+			MTU:              config.GetInt(config.FlagWireguardMTU),
 		}
 		return wireguard_connection.NewConnection(opts, di.IPResolver, endpointFactory, handshakeWaiter)
 	}
@@ -452,6 +454,7 @@ func (di *Dependencies) registerDataTransferConnection(nodeOptions node.Options,
 		opts := wireguard_connection.Options{
 			DNSScriptDir:     nodeOptions.Directories.Script,
 			HandshakeTimeout: 1 * time.Minute,
+			MTU:              config.GetInt(config.FlagWireguardMTU),
 		}
 		return wireguard_connection.NewConnection(opts, di.IPResolver, endpointFactory, handshakeWaiter)
 	}
@@ -468,6 +471,7 @@ func (di *Dependencies) registerDVPNConnection(nodeOptions node.Options, resourc
 		opts := wireguard_connection.Options{
 			DNSScriptDir:     nodeOptions.Directories.Script,
 			HandshakeTimeout: 1 * time.Minute,
+			MTU:              config.GetInt(config.FlagWireguardMTU),
 		}
 		return wireguard_connection.NewConnection(opts, di.IPResolver, endpointFactory, handshakeWaiter)
 	}
@@ -484,6 +488,7 @@ func (di *Dependencies) registerMonitoringConnection(nodeOptions node.Options, r
 		opts := wireguard_connection.Options{
 			DNSScriptDir:     nodeOptions.Directories.Script,
 			HandshakeTimeout: 1 * time.Minute,
+			MTU:              config.GetInt(config.FlagWireguardMTU),
 		}
 		return wireguard_connection.NewConnection(opts, di.IPResolver, endpointFactory, handshakeWaiter)
 	}
@@ -20,6 +20,8 @@ package config
 import (
 	"math/big"
 	"os"
+	"path/filepath"
+	"runtime"
 	"strings"
 	"sync"
 	"time"
@@ -107,7 +109,7 @@ func (cfg *Config) SaveUserConfig() error {
 	if err != nil {
 		return errors.Wrap(err, "failed to write configuration as toml")
 	}
-	err = os.WriteFile(cfg.userConfigLocation, []byte(out.String()), 0700)
+	err = writeFileAtomic(cfg.userConfigLocation, []byte(out.String()), 0700)
 	if err != nil {
 		return errors.Wrap(err, "failed to write configuration to file")
 	}
@@ -119,6 +121,56 @@ func (cfg *Config) SaveUserConfig() error {
 	return nil
 }
 
+// writeFileAtomic writes data to a temporary file next to the target, syncs it and renames it over the target,
+// so a crash mid-write leaves either the old or the new file, never a truncated one.
+func writeFileAtomic(filename string, data []byte, perm os.FileMode) (err error) {
+	tmp, err := os.CreateTemp(filepath.Dir(filename), filepath.Base(filename)+".*.tmp")
+	if err != nil {
+		return err
+	}
+	defer func() {
+		if err != nil {
+			os.Remove(tmp.Name())
+		}
+	}()
+
+	if _, err = tmp.Write(data); err != nil {
+		tmp.Close()
+		return err
+	}
+	if err = tmp.Sync(); err != nil {
+		tmp.Close()
+		return err
+	}
+	if err = tmp.Close(); err != nil {
+		return err
+	}
+	if err = os.Chmod(tmp.Name(), perm); err != nil {
+		return err
+	}
+	if err = os.Rename(tmp.Name(), filename); err != nil {
+		return err
+	}
+	return syncDir(filepath.Dir(filename))
+}
+
+// syncDir flushes the directory entry, so the rename survives a power loss.
+// Directories can not be opened for syncing on Windows, so it is skipped there.
+func syncDir(dir string) error {
+	if runtime.GOOS == "windows" {
+		return nil
+	}
+	d, err := os.Open(dir)
+	if err != nil {
+		return err
+	}
+	if err := d.Sync(); err != nil {
+		d.Close()
+		return err
+	}
+	return d.Close()
+}
+
 // GetDefaultConfig returns default configuration.
 func (cfg *Config) GetDefaultConfig() map[string]interface{} {
 	cfg.mu.RLock()
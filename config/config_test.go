@@ -20,6 +20,7 @@ package config
 import (
 	"flag"
 	"os"
+	"path/filepath"
 	"strings"
 	"testing"
 
@@ -85,6 +86,23 @@ func TestUserConfig_Save(t *testing.T) {
 	assert.NotContains(t, string(tomlContent), `proto = "tcp"`)
 }
 
+func TestWriteFileAtomic_ReplacesFileWithoutLeftovers(t *testing.T) {
+	dir := t.TempDir()
+	filename := filepath.Join(dir, "config.toml")
+	assert.NoError(t, os.WriteFile(filename, []byte("old"), 0700))
+
+	err := writeFileAtomic(filename, []byte("new"), 0700)
+	assert.NoError(t, err)
+
+	content, err := os.ReadFile(filename)
+	assert.NoError(t, err)
+	assert.Equal(t, "new", string(content))
+
+	entries, err := os.ReadDir(dir)
+	assert.NoError(t, err)
+	assert.Len(t, entries, 1)
+}
+
 func NewTempFileName(t *testing.T) string {
 	file, err := os.CreateTemp("", "*")
 	assert.NoError(t, err)
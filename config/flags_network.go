@@ -108,6 +108,12 @@ var (
 		Usage: "Restore connection automatically once it failed",
 		Value: false,
 	}
+	// FlagDataCap limits the amount of data a single connection is allowed to transfer.
+	FlagDataCap = cli.StringFlag{
+		Name:  "data-cap",
+		Usage: "Disconnect once the connection transfers more than the given amount of data (e.g. 10GB, 512MiB). Unlimited if empty",
+		Value: "",
+	}
 	// FlagSTUNservers list of STUN server to be used to detect NAT type.
 	FlagSTUNservers = cli.StringSliceFlag{
 		Name:  "stun-servers",
@@ -172,6 +178,7 @@ func RegisterFlagsNetwork(flags *[]cli.Flag) {
 		&FlagChainID,
 		&FlagKeepConnectedOnFail,
 		&FlagAutoReconnect,
+		&FlagDataCap,
 		&FlagSTUNservers,
 		&FlagLocalServiceDiscovery,
 		&FlagUDPListenPorts,
@@ -196,6 +203,7 @@ func ParseFlagsNetwork(ctx *cli.Context) {
 	Current.ParseInt64Flag(ctx, FlagChainID)
 	Current.ParseBoolFlag(ctx, FlagKeepConnectedOnFail)
 	Current.ParseBoolFlag(ctx, FlagAutoReconnect)
+	Current.ParseStringFlag(ctx, FlagDataCap)
 	Current.ParseStringSliceFlag(ctx, FlagSTUNservers)
 	Current.ParseBoolFlag(ctx, FlagLocalServiceDiscovery)
 	Current.ParseStringFlag(ctx, FlagUDPListenPorts)
@@ -178,12 +178,36 @@ var (
 		Usage: "Comma separated list of allowed domains. Prepend value with dot for wildcard mask",
 		Value: ".localhost, localhost, .localdomain",
 	}
+	// FlagTequilapiAllowedOrigins allows cross-origin requests from the following origins.
+	FlagTequilapiAllowedOrigins = cli.StringFlag{
+		Name:  "tequilapi.allowed-origins",
+		Usage: "Comma separated list of origins (e.g. https://dashboard.example.com) allowed to make cross-origin requests. Empty allows same origin only",
+		Value: "",
+	}
+	// FlagTequilapiAllowedMethods restricts methods allowed in cross-origin requests.
+	FlagTequilapiAllowedMethods = cli.StringFlag{
+		Name:  "tequilapi.allowed-methods",
+		Usage: "Comma separated list of HTTP methods allowed in cross-origin requests",
+		Value: "GET, POST, PUT, DELETE",
+	}
+	// FlagTequilapiAllowCredentials allows cross-origin requests to include credentials.
+	FlagTequilapiAllowCredentials = cli.BoolFlag{
+		Name:  "tequilapi.allow-credentials",
+		Usage: "Allow cross-origin requests from allowed origins to include credentials, e.g. cookies",
+		Value: false,
+	}
 	// FlagTequilapiPort port for listening for incoming API requests.
 	FlagTequilapiPort = cli.IntFlag{
 		Name:  "tequilapi.port",
 		Usage: "Port for listening incoming API requests",
 		Value: 4050,
 	}
+	// FlagTequilapiSocket unix socket path for listening for incoming API requests.
+	FlagTequilapiSocket = cli.StringFlag{
+		Name:  "tequilapi.socket",
+		Usage: "Path of unix socket to serve API on instead of TCP address and port. Web UI is not able to reach the API then",
+		Value: "",
+	}
 	// FlagTequilapiDebugMode debug mode for tequilapi.
 	FlagTequilapiDebugMode = cli.BoolFlag{
 		Name:  "tequilapi.debug",
@@ -298,6 +322,37 @@ var (
 		Name:  "wireguard.mtu",
 		Usage: "Wireguard interface MTU",
 	}
+
+	// FlagWireguardMSSClamp enables TCP MSS clamping for traffic forwarded through provider tunnels.
+	FlagWireguardMSSClamp = cli.BoolFlag{
+		Name:  "wireguard.mss-clamp",
+		Usage: "Clamp TCP MSS of connections forwarded through provider tunnels to the path MTU, for paths which blackhole large segments",
+		Value: false,
+	}
+	// FlagWebhookURLs webhook URLs notified about node events.
+	FlagWebhookURLs = cli.StringFlag{
+		Name:  "webhook.urls",
+		Usage: "Comma separated list of http(s) URLs to POST connection state changes, payment failures and low balance notices to",
+		Value: "",
+	}
+	// FlagWebhookSecret secret used to sign webhook requests.
+	FlagWebhookSecret = cli.StringFlag{
+		Name:  "webhook.secret",
+		Usage: "Secret for signing webhook request bodies with HMAC-SHA256",
+		Value: "",
+	}
+	// FlagWebhookBalanceLow balance in MYST below which webhooks are notified.
+	FlagWebhookBalanceLow = cli.Float64Flag{
+		Name:  "webhook.balance-low",
+		Usage: "Notify webhooks when identity balance drops below this amount of MYST, 0 disables the notice",
+		Value: 1,
+	}
+	// FlagSessionHistoryLimit limits number of sessions kept in history.
+	FlagSessionHistoryLimit = cli.IntFlag{
+		Name:  "session-history.limit",
+		Usage: "Maximum number of sessions kept in history, oldest completed sessions are removed first (0 - unlimited)",
+		Value: 0,
+	}
 )
 
 // RegisterFlagsNode function register node flags to flag list
@@ -318,6 +373,7 @@ func RegisterFlagsNode(flags *[]cli.Flag) error {
 	RegisterFlagsUI(flags)
 	RegisterFlagsBlockchainNetwork(flags)
 	RegisterFlagsSSE(flags)
+	RegisterFlagsProxy(flags)
 	RegisterFlagsServiceQuic(flags)
 
 	*flags = append(*flags,
@@ -343,7 +399,11 @@ func RegisterFlagsNode(flags *[]cli.Flag) error {
 		&FlagQualityAddress,
 		&FlagTequilapiAddress,
 		&FlagTequilapiAllowedHostnames,
+		&FlagTequilapiAllowedOrigins,
+		&FlagTequilapiAllowedMethods,
+		&FlagTequilapiAllowCredentials,
 		&FlagTequilapiPort,
+		&FlagTequilapiSocket,
 		&FlagTequilapiUsername,
 		&FlagTequilapiPassword,
 		&FlagPProfEnable,
@@ -360,6 +420,11 @@ func RegisterFlagsNode(flags *[]cli.Flag) error {
 		&FlagDNSResolutionHeadstart,
 		&FlagResidentCountry,
 		&FlagWireguardMTU,
+		&FlagWireguardMSSClamp,
+		&FlagWebhookURLs,
+		&FlagWebhookSecret,
+		&FlagWebhookBalanceLow,
+		&FlagSessionHistoryLimit,
 	)
 
 	return nil
@@ -380,6 +445,7 @@ func ParseFlagsNode(ctx *cli.Context) {
 	ParseFlagsChains(ctx)
 	ParseFlagsUI(ctx)
 	ParseFlagsSSE(ctx)
+	ParseFlagsProxy(ctx)
 	// it is important to have this one at the end so it overwrites defaults correctly
 	ParseFlagsServiceQuic(ctx)
 	ParseFlagsBlockchainNetwork(ctx)
@@ -406,7 +472,11 @@ func ParseFlagsNode(ctx *cli.Context) {
 	Current.ParseStringFlag(ctx, FlagQualityType)
 	Current.ParseStringFlag(ctx, FlagTequilapiAddress)
 	Current.ParseStringFlag(ctx, FlagTequilapiAllowedHostnames)
+	Current.ParseStringFlag(ctx, FlagTequilapiAllowedOrigins)
+	Current.ParseStringFlag(ctx, FlagTequilapiAllowedMethods)
+	Current.ParseBoolFlag(ctx, FlagTequilapiAllowCredentials)
 	Current.ParseIntFlag(ctx, FlagTequilapiPort)
+	Current.ParseStringFlag(ctx, FlagTequilapiSocket)
 	Current.ParseStringFlag(ctx, FlagTequilapiUsername)
 	Current.ParseStringFlag(ctx, FlagTequilapiPassword)
 	Current.ParseBoolFlag(ctx, FlagPProfEnable)
@@ -422,6 +492,11 @@ func ParseFlagsNode(ctx *cli.Context) {
 	Current.ParseStringFlag(ctx, FlagDocsURL)
 	Current.ParseDurationFlag(ctx, FlagDNSResolutionHeadstart)
 	Current.ParseIntFlag(ctx, FlagWireguardMTU)
+	Current.ParseBoolFlag(ctx, FlagWireguardMSSClamp)
+	Current.ParseStringFlag(ctx, FlagWebhookURLs)
+	Current.ParseStringFlag(ctx, FlagWebhookSecret)
+	Current.ParseFloat64Flag(ctx, FlagWebhookBalanceLow)
+	Current.ParseIntFlag(ctx, FlagSessionHistoryLimit)
 
 	ValidateAddressFlags(FlagTequilapiAddress)
 }
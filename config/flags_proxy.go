@@ -0,0 +1,117 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package config
+
+import (
+	"time"
+
+	"github.com/urfave/cli/v2"
+)
+
+var (
+	// FlagProxyDrainTimeout sets how long in-flight proxy tunnels are allowed to finish on shutdown.
+	FlagProxyDrainTimeout = cli.DurationFlag{
+		Name:  "proxy.drain-timeout",
+		Usage: "Time given to in-flight proxy tunnels to finish on shutdown before they are cut",
+		Value: 20 * time.Second,
+	}
+	// FlagProxyBufferSize sets the size of buffers used to copy proxied traffic.
+	FlagProxyBufferSize = cli.IntFlag{
+		Name:  "proxy.buffer-size",
+		Usage: "Size in bytes of buffers used to copy proxied traffic, larger buffers favour throughput over memory",
+		Value: 128 * 1024,
+	}
+	// FlagProxyConnectTimeout limits a single outbound connection attempt.
+	FlagProxyConnectTimeout = cli.DurationFlag{
+		Name:  "proxy.connect-timeout",
+		Usage: "Time given to a single outbound connection attempt, so unreachable targets fail fast. Disabled if 0",
+		Value: 5 * time.Second,
+	}
+	// FlagProxyDialRetries sets how many times a failed outbound dial is retried.
+	FlagProxyDialRetries = cli.IntFlag{
+		Name:  "proxy.dial-retries",
+		Usage: "Number of times a failed outbound connection is retried before the local client is rejected",
+		Value: 2,
+	}
+	// FlagProxyDialBackoff sets the initial delay between outbound dial retries.
+	FlagProxyDialBackoff = cli.DurationFlag{
+		Name:  "proxy.dial-backoff",
+		Usage: "Initial delay between outbound connection retries, grows exponentially",
+		Value: 250 * time.Millisecond,
+	}
+	// FlagProxyDialQueue limits how many outbound dials may be in progress at once.
+	FlagProxyDialQueue = cli.IntFlag{
+		Name:  "proxy.dial-queue",
+		Usage: "Number of outbound connections which may be dialed or wait for a retry at once, further local clients are rejected right away. Set 0 for no limit",
+		Value: 64,
+	}
+	// FlagProxyTrafficLog enables debug logging of proxied traffic metadata into the given file.
+	FlagProxyTrafficLog = cli.StringFlag{
+		Name:  "proxy.traffic-log",
+		Usage: "Debug only: append metadata of proxied traffic as JSON lines to the given file. The file reveals visited hosts, do not share it carelessly",
+	}
+	// FlagProxyTrafficLogPorts limits the traffic log to the given destination ports.
+	FlagProxyTrafficLogPorts = cli.StringFlag{
+		Name:  "proxy.traffic-log-ports",
+		Usage: "Comma separated list of destination ports to log, nothing is logged if empty",
+		Value: "",
+	}
+	// FlagProxyTrafficLogPayload sets how many payload bytes per direction are kept by the traffic log.
+	FlagProxyTrafficLogPayload = cli.IntFlag{
+		Name:  "proxy.traffic-log-payload",
+		Usage: "Number of payload bytes per direction to include in the traffic log, payload is not logged if 0",
+		Value: 0,
+	}
+	// FlagProxySocketDir makes the proxy listen on unix sockets in the given directory instead of TCP ports.
+	FlagProxySocketDir = cli.StringFlag{
+		Name:  "proxy.socket-dir",
+		Usage: "Serve proxy on a unix socket proxy-<port>.sock in the given directory instead of a TCP port, access is limited to the node user",
+		Value: "",
+	}
+)
+
+// RegisterFlagsProxy function register proxy mode flags to flag list
+func RegisterFlagsProxy(flags *[]cli.Flag) {
+	*flags = append(
+		*flags,
+		&FlagProxyDrainTimeout,
+		&FlagProxyBufferSize,
+		&FlagProxyConnectTimeout,
+		&FlagProxyDialRetries,
+		&FlagProxyDialBackoff,
+		&FlagProxyDialQueue,
+		&FlagProxyTrafficLog,
+		&FlagProxyTrafficLogPorts,
+		&FlagProxyTrafficLogPayload,
+		&FlagProxySocketDir,
+	)
+}
+
+// ParseFlagsProxy function fills in proxy mode options from CLI context
+func ParseFlagsProxy(ctx *cli.Context) {
+	Current.ParseDurationFlag(ctx, FlagProxyDrainTimeout)
+	Current.ParseIntFlag(ctx, FlagProxyBufferSize)
+	Current.ParseDurationFlag(ctx, FlagProxyConnectTimeout)
+	Current.ParseIntFlag(ctx, FlagProxyDialRetries)
+	Current.ParseDurationFlag(ctx, FlagProxyDialBackoff)
+	Current.ParseIntFlag(ctx, FlagProxyDialQueue)
+	Current.ParseStringFlag(ctx, FlagProxyTrafficLog)
+	Current.ParseStringFlag(ctx, FlagProxyTrafficLogPorts)
+	Current.ParseIntFlag(ctx, FlagProxyTrafficLogPayload)
+	Current.ParseStringFlag(ctx, FlagProxySocketDir)
+}
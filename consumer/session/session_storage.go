@@ -41,18 +41,21 @@ type timeGetter func() time.Time
 
 // Storage contains functions for storing, getting session objects.
 type Storage struct {
-	storage    *boltdb.Bolt
-	timeGetter timeGetter
+	storage      *boltdb.Bolt
+	timeGetter   timeGetter
+	historyLimit int
 
 	mu             sync.RWMutex
 	sessionsActive map[session_node.ID]History
 }
 
 // NewSessionStorage creates session repository with given dependencies.
-func NewSessionStorage(storage *boltdb.Bolt) *Storage {
+// When historyLimit is positive, oldest completed sessions exceeding it are removed.
+func NewSessionStorage(storage *boltdb.Bolt, historyLimit int) *Storage {
 	return &Storage{
-		storage:    storage,
-		timeGetter: time.Now,
+		storage:      storage,
+		timeGetter:   time.Now,
+		historyLimit: historyLimit,
 
 		sessionsActive: make(map[session_node.ID]History),
 	}
@@ -314,6 +317,40 @@ func (repo *Storage) handleEndedEvent(sessionID session_node.ID) {
 
 	delete(repo.sessionsActive, sessionID)
 	log.Debug().Msgf("Session %v updated with final data", sessionID)
+
+	repo.pruneHistory()
+}
+
+func (repo *Storage) pruneHistory() {
+	if repo.historyLimit <= 0 {
+		return
+	}
+
+	var expired []History
+	repo.storage.RLock()
+	err := repo.storage.DB().
+		From(sessionStorageBucketName).
+		Select().
+		OrderBy("Started").
+		Reverse().
+		Skip(repo.historyLimit).
+		Find(&expired)
+	repo.storage.RUnlock()
+	if err != nil {
+		if !errors.Is(err, storm.ErrNotFound) {
+			log.Error().Err(err).Msg("Could not list sessions exceeding history limit")
+		}
+		return
+	}
+
+	for i := range expired {
+		if expired[i].Status != StatusCompleted {
+			continue
+		}
+		if err := repo.storage.Delete(sessionStorageBucketName, &expired[i]); err != nil {
+			log.Error().Err(err).Msgf("Could not remove session %v from history", expired[i].SessionID)
+		}
+	}
 }
 
 func (repo *Storage) handleCreatedEvent(sessionID session_node.ID) {
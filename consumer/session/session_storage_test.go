@@ -375,6 +375,35 @@ func TestSessionStorage_consumeEventEndedOK(t *testing.T) {
 	)
 }
 
+func TestSessionStorage_historyLimitRemovesOldestCompleted(t *testing.T) {
+	// given
+	storage, storageCleanup := newStorageWithLimit(1)
+	defer storageCleanup()
+
+	older := connectionSessionMock
+	newer := connectionSessionMock
+	newer.SessionID = "sessionID2"
+	newer.StartedAt = older.StartedAt.Add(time.Hour)
+
+	// when
+	for _, session := range []connectionstate.Status{older, newer} {
+		storage.consumeConnectionSessionEvent(connectionstate.AppEventConnectionSession{
+			Status:      connectionstate.SessionCreatedStatus,
+			SessionInfo: session,
+		})
+		storage.consumeConnectionSessionEvent(connectionstate.AppEventConnectionSession{
+			Status:      connectionstate.SessionEndedStatus,
+			SessionInfo: session,
+		})
+	}
+
+	// then
+	sessions, err := storage.GetAll()
+	assert.Nil(t, err)
+	assert.Len(t, sessions, 1)
+	assert.Equal(t, session_node.ID("sessionID2"), sessions[0].SessionID)
+}
+
 func TestSessionStorage_consumeEventConnectedOK(t *testing.T) {
 	// given
 	storage, storageCleanup := newStorage()
@@ -482,6 +511,10 @@ func TestSessionStorage_consumeSessionSpendingEvent(t *testing.T) {
 }
 
 func newStorage() (*Storage, func()) {
+	return newStorageWithLimit(0)
+}
+
+func newStorageWithLimit(historyLimit int) (*Storage, func()) {
 	dir, err := os.MkdirTemp("", "sessionStorageTest")
 	if err != nil {
 		panic(err)
@@ -492,7 +525,7 @@ func newStorage() (*Storage, func()) {
 		panic(err)
 	}
 
-	return NewSessionStorage(db), func() {
+	return NewSessionStorage(db, historyLimit), func() {
 		err := db.Close()
 		if err != nil {
 			panic(err)
@@ -36,6 +36,8 @@ const (
 	AppTopicConnectionStatistics = "Statistics"
 	// AppTopicConnectionSession represents the session lifetime changes
 	AppTopicConnectionSession = "Session"
+	// AppTopicConnectionPaymentFailed represents the failures of paying for the session
+	AppTopicConnectionPaymentFailed = "PaymentFailed"
 )
 
 // AppEventConnectionState is the struct we'll emit on a AppEventConnectionState topic event
@@ -45,6 +47,13 @@ type AppEventConnectionState struct {
 	SessionInfo Status
 }
 
+// AppEventConnectionPaymentFailed is the struct we'll emit on a AppTopicConnectionPaymentFailed topic event
+type AppEventConnectionPaymentFailed struct {
+	UUID        string
+	SessionInfo Status
+	Error       string
+}
+
 // State represents list of possible connection states
 type State string
 
@@ -80,6 +89,10 @@ type Status struct {
 	State            State
 	SessionID        session.ID
 	Proposal         proposal.PricedServiceProposal
+	// ProxyPort identifies connection among the ones managed at the same time, zero for the main one.
+	ProxyPort int
+	// DisconnectReason explains why the node closed the connection on its own, empty otherwise.
+	DisconnectReason string
 }
 
 // Duration returns elapsed time from marked session start
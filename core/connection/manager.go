@@ -36,6 +36,7 @@ import (
 	"github.com/mysteriumnetwork/node/core/ip"
 	"github.com/mysteriumnetwork/node/core/location"
 	"github.com/mysteriumnetwork/node/core/quality"
+	"github.com/mysteriumnetwork/node/datasize"
 	"github.com/mysteriumnetwork/node/eventbus"
 	"github.com/mysteriumnetwork/node/firewall"
 	"github.com/mysteriumnetwork/node/identity"
@@ -49,6 +50,9 @@ import (
 
 const (
 	p2pDialTimeout = 60 * time.Second
+
+	// DisconnectReasonDataCap is recorded in connection status when the data cap is reached.
+	DisconnectReasonDataCap = "data cap reached"
 )
 
 var (
@@ -85,6 +89,8 @@ type KeepAliveConfig struct {
 type Config struct {
 	IPCheck   IPCheckConfig
 	KeepAlive KeepAliveConfig
+	// DataCap disconnects the connection once it transfers more data, zero means unlimited.
+	DataCap datasize.BitSize
 }
 
 // DefaultConfig returns default params.
@@ -261,7 +267,7 @@ func (m *connectionManager) Connect(consumerID identity.Identity, hermesID commo
 	m.ctx, m.cancel = context.WithCancel(context.Background())
 	m.ctxLock.Unlock()
 
-	m.statusConnecting(consumerID, hermesID, *proposal)
+	m.statusConnecting(consumerID, hermesID, *proposal, params.ProxyPort)
 	defer func() {
 		if err != nil {
 			log.Err(err).Msg("Connect failed, disconnecting")
@@ -311,6 +317,9 @@ func (m *connectionManager) Connect(consumerID identity.Identity, hermesID commo
 	go m.consumeConnectionStates(m.activeConnection.State())
 	go m.checkSessionIP(m.channel, m.connectOptions.ConsumerID, m.connectOptions.SessionID, originalPublicIP)
 	go m.monitorPrice(prc)
+	if m.config.DataCap > 0 {
+		go m.monitorDataCap(m.config.DataCap)
+	}
 
 	return nil
 }
@@ -507,6 +516,7 @@ func (m *connectionManager) paymentLoop(opts ConnectOptions, price market.Price)
 		err := payments.Start()
 		if err != nil {
 			log.Error().Err(err).Msg("Payment error")
+			m.publishPaymentFailed(err)
 
 			if config.GetBool(config.FlagKeepConnectedOnFail) {
 				m.statusOnHold()
@@ -754,7 +764,7 @@ func (m *connectionManager) setStatus(delta func(status *connectionstate.Status)
 	}
 }
 
-func (m *connectionManager) statusConnecting(consumerID identity.Identity, accountantID common.Address, proposal proposal.PricedServiceProposal) {
+func (m *connectionManager) statusConnecting(consumerID identity.Identity, accountantID common.Address, proposal proposal.PricedServiceProposal, proxyPort int) {
 	m.setStatus(func(status *connectionstate.Status) {
 		*status = connectionstate.Status{
 			StartedAt:        m.timeGetter(),
@@ -762,6 +772,7 @@ func (m *connectionManager) statusConnecting(consumerID identity.Identity, accou
 			ConsumerLocation: m.locationResolver.GetOrigin(),
 			HermesID:         accountantID,
 			Proposal:         proposal,
+			ProxyPort:        proxyPort,
 			State:            connectionstate.Connecting,
 		}
 	})
@@ -950,6 +961,18 @@ func (m *connectionManager) publishStateEvent(state connectionstate.State) {
 	})
 }
 
+func (m *connectionManager) publishPaymentFailed(err error) {
+	sessionInfo := m.Status()
+	// avoid printing IP address in logs
+	sessionInfo.ConsumerLocation.IP = ""
+
+	m.eventBus.Publish(connectionstate.AppTopicConnectionPaymentFailed, connectionstate.AppEventConnectionPaymentFailed{
+		UUID:        m.uuid,
+		SessionInfo: sessionInfo,
+		Error:       err.Error(),
+	})
+}
+
 func (m *connectionManager) keepAliveLoop(channel p2p.Channel, sessionID session.ID) {
 	// Register handler for handling p2p keep alive pings from provider.
 	channel.Handle(p2p.TopicKeepAlive, func(c p2p.Context) error {
@@ -1064,3 +1087,27 @@ func (m *connectionManager) monitorPrice(currentPrice market.Price) {
 		}
 	}
 }
+
+func (m *connectionManager) monitorDataCap(dataCap datasize.BitSize) {
+	t := time.NewTicker(m.statsReportInterval)
+	defer t.Stop()
+	for {
+		select {
+		case <-m.currentCtx().Done():
+			return
+		case <-t.C:
+			stats := m.Stats()
+			transferred := datasize.FromBytes(stats.BytesSent + stats.BytesReceived)
+			if transferred < dataCap {
+				continue
+			}
+
+			log.Info().Msgf("Data cap of %s reached with %s transferred, disconnecting", dataCap, transferred)
+			m.setStatus(func(status *connectionstate.Status) {
+				status.DisconnectReason = DisconnectReasonDataCap
+			})
+			logDisconnectError(m.Disconnect())
+			return
+		}
+	}
+}
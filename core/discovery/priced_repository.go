@@ -69,6 +69,11 @@ func (pspr *PricedServiceProposalRepository) Proposals(filter *proposal.Filter)
 	}
 	priced := pspr.toPricedProposals(proposals)
 
+	// Prices are only known once proposals are enriched, so price limits can't be matched by the base repository.
+	if filter != nil {
+		priced = filterByPrice(priced, filter)
+	}
+
 	if filter != nil && filter.PresetID != 0 {
 		preset, err := pspr.filterPresets.Get(filter.PresetID)
 		if err != nil {
@@ -104,6 +109,17 @@ func (pspr *PricedServiceProposalRepository) toPricedProposals(in []market.Servi
 	return res
 }
 
+func filterByPrice(in []proposal.PricedServiceProposal, filter *proposal.Filter) []proposal.PricedServiceProposal {
+	res := make([]proposal.PricedServiceProposal, 0, len(in))
+	for _, p := range in {
+		if filter.MatchesPrice(p.Price) {
+			res = append(res, p)
+		}
+	}
+
+	return res
+}
+
 func (pspr *PricedServiceProposalRepository) toPricedProposal(in market.ServiceProposal) (proposal.PricedServiceProposal, error) {
 	price, err := pspr.pip.GetCurrentPrice(in.Location.IPType, in.Location.Country, in.ServiceType)
 	if err != nil {
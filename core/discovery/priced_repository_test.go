@@ -125,6 +125,25 @@ func TestGetProposals(t *testing.T) {
 		assert.EqualValues(t, mockProposal, result[0].ServiceProposal)
 		assert.EqualValues(t, mockPrice, result[0].Price)
 	})
+	t.Run("filters by price limits", func(t *testing.T) {
+		mp := &mockPriceInfoProvider{
+			priceToReturn: market.Price{
+				PricePerHour: big.NewInt(10),
+				PricePerGiB:  big.NewInt(20),
+			},
+		}
+		repo := NewPricedServiceProposalRepository(&mockRepository{
+			proposalsToReturn: []market.ServiceProposal{mockProposal},
+		}, mp, presetRepository)
+
+		result, err := repo.Proposals(&proposal.Filter{PriceHourMax: big.NewInt(10), PriceGiBMax: big.NewInt(20)})
+		assert.NoError(t, err)
+		assert.Len(t, result, 1)
+
+		result, err = repo.Proposals(&proposal.Filter{PriceGiBMax: big.NewInt(19)})
+		assert.NoError(t, err)
+		assert.Len(t, result, 0)
+	})
 	t.Run("bubbles repo errors", func(t *testing.T) {
 		mockError := errors.New("boom")
 		mr := &mockRepository{
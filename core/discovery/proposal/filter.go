@@ -18,6 +18,7 @@
 package proposal
 
 import (
+	"math/big"
 	"sync"
 
 	"github.com/mysteriumnetwork/node/core/discovery/reducer"
@@ -41,6 +42,7 @@ type Filter struct {
 	ExcludeUnsupported                 bool
 	IncludeMonitoringFailed            bool
 	NATCompatibility                   nat.NATType
+	PriceHourMax, PriceGiBMax          *big.Int
 	condition                          reducer.AndCondition
 	buildOnce                          sync.Once
 }
@@ -87,6 +89,15 @@ func (filter *Filter) Matches(proposal market.ServiceProposal) bool {
 	return filter.condition(proposal)
 }
 
+// MatchesPrice return flag if given price is within price limits of filter
+func (filter *Filter) MatchesPrice(price market.Price) bool {
+	return !exceedsLimit(price.PricePerHour, filter.PriceHourMax) && !exceedsLimit(price.PricePerGiB, filter.PriceGiBMax)
+}
+
+func exceedsLimit(price, limit *big.Int) bool {
+	return limit != nil && price != nil && price.Cmp(limit) > 0
+}
+
 // ToAPIQuery serialises filter to query of Mysterium API
 func (filter *Filter) ToAPIQuery() mysterium.ProposalsQuery {
 	query := mysterium.ProposalsQuery{
@@ -18,6 +18,7 @@
 package proposal
 
 import (
+	"math/big"
 	"testing"
 
 	"github.com/mysteriumnetwork/node/market"
@@ -161,3 +162,12 @@ func Test_ProposalFilter_Filters_Unsupported(t *testing.T) {
 	assert.False(t, filter.Matches(proposalEmpty))
 	assert.True(t, filter.Matches(proposalSupported))
 }
+
+func Test_ProposalFilter_MatchesPrice(t *testing.T) {
+	price := market.Price{PricePerHour: big.NewInt(10), PricePerGiB: big.NewInt(20)}
+
+	assert.True(t, (&Filter{}).MatchesPrice(price))
+	assert.True(t, (&Filter{PriceHourMax: big.NewInt(10), PriceGiBMax: big.NewInt(20)}).MatchesPrice(price))
+	assert.False(t, (&Filter{PriceHourMax: big.NewInt(9)}).MatchesPrice(price))
+	assert.False(t, (&Filter{PriceGiBMax: big.NewInt(19)}).MatchesPrice(price))
+}
@@ -56,14 +56,18 @@ var _ Openvpn = wrapper{}
 type Options struct {
 	Directories OptionsDirectory
 
-	TequilapiAddress       string
-	TequilapiPort          int
-	FlagTequilapiDebugMode bool
-	TequilapiEnabled       bool
-	TequilapiSecured       bool
-	BindAddress            string
-	UI                     OptionsUI
-	FeedbackURL            string
+	TequilapiAddress          string
+	TequilapiPort             int
+	TequilapiSocket           string
+	TequilapiAllowedOrigins   string
+	TequilapiAllowedMethods   string
+	TequilapiAllowCredentials bool
+	FlagTequilapiDebugMode    bool
+	TequilapiEnabled          bool
+	TequilapiSecured          bool
+	BindAddress               string
+	UI                        OptionsUI
+	FeedbackURL               string
 
 	Keystore OptionsKeystore
 
@@ -113,12 +117,16 @@ func GetOptions() *Options {
 		},
 	}
 	return &Options{
-		Directories:            *GetOptionsDirectory(&network),
-		TequilapiAddress:       config.GetString(config.FlagTequilapiAddress),
-		TequilapiPort:          config.GetInt(config.FlagTequilapiPort),
-		FlagTequilapiDebugMode: config.GetBool(config.FlagTequilapiDebugMode),
-		TequilapiEnabled:       true,
-		BindAddress:            config.GetString(config.FlagBindAddress),
+		Directories:               *GetOptionsDirectory(&network),
+		TequilapiAddress:          config.GetString(config.FlagTequilapiAddress),
+		TequilapiPort:             config.GetInt(config.FlagTequilapiPort),
+		TequilapiSocket:           config.GetString(config.FlagTequilapiSocket),
+		TequilapiAllowedOrigins:   config.GetString(config.FlagTequilapiAllowedOrigins),
+		TequilapiAllowedMethods:   config.GetString(config.FlagTequilapiAllowedMethods),
+		TequilapiAllowCredentials: config.GetBool(config.FlagTequilapiAllowCredentials),
+		FlagTequilapiDebugMode:    config.GetBool(config.FlagTequilapiDebugMode),
+		TequilapiEnabled:          true,
+		BindAddress:               config.GetString(config.FlagBindAddress),
 		UI: OptionsUI{
 			UIEnabled:     config.GetBool(config.FlagUIEnable),
 			UIBindAddress: config.GetString(config.FlagUIAddress),
@@ -78,6 +78,17 @@ func (sp *SessionPool) Find(id session.ID) (*Session, bool) {
 	return instance, found
 }
 
+// Kill closes the given session, disconnecting its consumer. Returns false if there is no such session.
+func (sp *SessionPool) Kill(id session.ID) bool {
+	instance, found := sp.Find(id)
+	if !found {
+		return false
+	}
+
+	instance.Close()
+	return true
+}
+
 // FindOpts provides fields to search sessions.
 type FindOpts struct {
 	Peer        *identity.Identity
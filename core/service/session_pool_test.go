@@ -55,6 +55,20 @@ func TestSessionPool_FindSession_Unknown(t *testing.T) {
 	assert.Nil(t, sessionInstance)
 }
 
+func TestSessionPool_Kill(t *testing.T) {
+	active, _ := NewSession(&Instance{}, &pb.SessionRequest{}, trace.NewTracer(""))
+	pool := mockPool(mocks.NewEventBus(), active)
+
+	assert.True(t, pool.Kill(active.ID))
+	select {
+	case <-active.Done():
+	default:
+		t.Error("session was not closed")
+	}
+
+	assert.False(t, pool.Kill(session.ID("unknown-id")))
+}
+
 func TestSessionPool_Add(t *testing.T) {
 	pool := mockPool(mocks.NewEventBus(), sessionExisting)
 	sessionNew, _ := NewSession(&Instance{}, &pb.SessionRequest{}, trace.NewTracer(""))
@@ -20,6 +20,7 @@ package event
 import (
 	"fmt"
 	"math/big"
+	"time"
 
 	"github.com/ethereum/go-ethereum/common"
 
@@ -67,6 +68,8 @@ type Connection struct {
 	Statistics connectionstate.Statistics
 	Throughput bandwidth.Throughput
 	Invoice    crypto.Invoice
+	// Latency is the last measured p2p keep-alive round trip time to the provider.
+	Latency time.Duration
 }
 
 func (c Connection) String() string {
@@ -76,7 +79,7 @@ func (c Connection) String() string {
 	}
 
 	return fmt.Sprintf(
-		"ID: %s, state: %s, duration: %s data: %s/%s, throughput: %s/%s, spent: %s",
+		"ID: %s, state: %s, duration: %s data: %s/%s, throughput: %s/%s, latency: %s, spent: %s",
 		c.Session.SessionID,
 		c.Session.State,
 		c.Session.Duration(),
@@ -84,6 +87,7 @@ func (c Connection) String() string {
 		datasize.FromBytes(c.Statistics.BytesSent),
 		c.Throughput.Down,
 		c.Throughput.Up,
+		c.Latency,
 		spent,
 	)
 }
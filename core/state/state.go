@@ -30,6 +30,7 @@ import (
 	"github.com/mysteriumnetwork/node/consumer/session"
 	"github.com/mysteriumnetwork/node/core/connection/connectionstate"
 	"github.com/mysteriumnetwork/node/core/discovery/proposal"
+	"github.com/mysteriumnetwork/node/core/quality"
 	"github.com/mysteriumnetwork/node/core/service"
 	"github.com/mysteriumnetwork/node/core/service/servicestate"
 	stateEvent "github.com/mysteriumnetwork/node/core/state/event"
@@ -197,6 +198,9 @@ func (k *Keeper) Subscribe(bus eventbus.Subscriber) error {
 	if err := bus.SubscribeAsync(pingpongEvent.AppTopicInvoicePaid, k.consumeConnectionSpendingEvent); err != nil {
 		return err
 	}
+	if err := bus.SubscribeAsync(quality.AppTopicConsumerPingP2P, k.consumeConnectionPingEvent); err != nil {
+		return err
+	}
 	if err := bus.SubscribeAsync(identity.AppTopicIdentityCreated, k.consumeIdentityCreatedEvent); err != nil {
 		return err
 	}
@@ -452,6 +456,25 @@ func (k *Keeper) updateConnectionSpending(e interface{}) {
 	go k.announceStateChanges(nil)
 }
 
+func (k *Keeper) consumeConnectionPingEvent(e interface{}) {
+	k.lock.Lock()
+	defer k.lock.Unlock()
+	evt, ok := e.(quality.PingEvent)
+	if !ok {
+		log.Warn().Msg("Received a wrong kind of event for connection ping update")
+		return
+	}
+
+	for uuid, conn := range k.state.Connections {
+		if string(conn.Session.SessionID) == evt.SessionID {
+			conn.Latency = evt.Duration
+			k.state.Connections[uuid] = conn
+			go k.announceStateChanges(nil)
+			return
+		}
+	}
+}
+
 func (k *Keeper) consumeBalanceChangedEvent(e interface{}) {
 	k.lock.Lock()
 	defer k.lock.Unlock()
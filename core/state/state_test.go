@@ -30,6 +30,7 @@ import (
 	"github.com/mysteriumnetwork/node/consumer/session"
 	"github.com/mysteriumnetwork/node/core/connection/connectionstate"
 	"github.com/mysteriumnetwork/node/core/discovery/proposal"
+	"github.com/mysteriumnetwork/node/core/quality"
 	"github.com/mysteriumnetwork/node/core/service"
 	"github.com/mysteriumnetwork/node/core/service/servicestate"
 	"github.com/mysteriumnetwork/node/datasize"
@@ -422,6 +423,43 @@ func Test_ConsumesConnectionInvoiceEvents(t *testing.T) {
 	}, 2*time.Second, 10*time.Millisecond)
 }
 
+func Test_ConsumesConnectionPingEvents(t *testing.T) {
+	// given
+	eventBus := eventbus.New()
+	deps := KeeperDeps{
+		Publisher:        eventBus,
+		ServiceLister:    &serviceListerMock{},
+		IdentityProvider: &mocks.IdentityProvider{},
+		EarningsProvider: &mockEarningsProvider{},
+	}
+	keeper := NewKeeper(deps, time.Millisecond)
+	err := keeper.Subscribe(eventBus)
+	assert.NoError(t, err)
+
+	eventBus.Publish(connectionstate.AppTopicConnectionState, connectionstate.AppEventConnectionState{
+		UUID:  "uuid",
+		State: connectionstate.Connected,
+		SessionInfo: connectionstate.Status{
+			State:     connectionstate.Connected,
+			SessionID: "1",
+		},
+	})
+	assert.Eventually(t, func() bool {
+		return keeper.GetConnection("1").Session.State == connectionstate.Connected
+	}, 2*time.Second, 10*time.Millisecond)
+
+	// when
+	eventBus.Publish(quality.AppTopicConsumerPingP2P, quality.PingEvent{
+		SessionID: "1",
+		Duration:  45 * time.Millisecond,
+	})
+
+	// then
+	assert.Eventually(t, func() bool {
+		return keeper.GetConnection("1").Latency == 45*time.Millisecond
+	}, 2*time.Second, 10*time.Millisecond)
+}
+
 func Test_ConsumesBalanceChangeEvent(t *testing.T) {
 	// given
 	eventBus := eventbus.New()
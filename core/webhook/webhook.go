@@ -0,0 +1,272 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package webhook
+
+import (
+	"bytes"
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"math/big"
+	"net/http"
+	"net/url"
+	"sync"
+	"time"
+
+	"github.com/rs/zerolog/log"
+
+	"github.com/mysteriumnetwork/node/core/connection/connectionstate"
+	"github.com/mysteriumnetwork/node/eventbus"
+	"github.com/mysteriumnetwork/node/session/pingpong/event"
+)
+
+// SignatureHeader carries HMAC-SHA256 signature of the request body, when secret is configured.
+const SignatureHeader = "X-Mysterium-Signature"
+
+const (
+	// EventTypeConnectionState is the type of event sent on connection state changes.
+	EventTypeConnectionState = "connection_state"
+	// EventTypePaymentFailed is the type of event sent when paying for the connection fails.
+	EventTypePaymentFailed = "payment_failed"
+	// EventTypeBalanceLow is the type of event sent when identity balance drops below the configured threshold.
+	EventTypeBalanceLow = "balance_low"
+)
+
+// queueSize is the number of events buffered per URL, newer events are dropped while the queue is full.
+const queueSize = 100
+
+// Event is the body posted to webhook URLs.
+type Event struct {
+	Type             string    `json:"type"`
+	State            string    `json:"state,omitempty"`
+	SessionID        string    `json:"session_id,omitempty"`
+	ConsumerID       string    `json:"consumer_id,omitempty"`
+	ProviderID       string    `json:"provider_id,omitempty"`
+	DisconnectReason string    `json:"disconnect_reason,omitempty"`
+	Error            string    `json:"error,omitempty"`
+	Balance          string    `json:"balance,omitempty"`
+	Timestamp        time.Time `json:"timestamp"`
+}
+
+type httpClient interface {
+	Do(req *http.Request) (*http.Response, error)
+}
+
+// Notifier posts node events to the configured webhook URLs.
+// Each URL is served by its own worker, so events are delivered to it in the order they happened
+// and a slow URL does not hold back the others nor the event publishers.
+type Notifier struct {
+	endpoints  []*endpoint
+	secret     string
+	balanceLow *big.Int
+	client     httpClient
+	attempts   int
+	retryDelay time.Duration
+
+	stop     chan struct{}
+	stopOnce sync.Once
+	wg       sync.WaitGroup
+}
+
+type endpoint struct {
+	url   string
+	queue chan Event
+}
+
+// NewNotifier returns notifier posting events to given http(s) URLs, signing them with secret if it is not empty.
+// BalanceLow events are sent when balance drops below balanceLow, nil disables them.
+func NewNotifier(urls []string, secret string, balanceLow *big.Int, client httpClient) (*Notifier, error) {
+	n := &Notifier{
+		secret:     secret,
+		balanceLow: balanceLow,
+		client:     client,
+		attempts:   3,
+		retryDelay: 2 * time.Second,
+		stop:       make(chan struct{}),
+	}
+	for _, rawURL := range urls {
+		if err := validateURL(rawURL); err != nil {
+			return nil, err
+		}
+		n.endpoints = append(n.endpoints, &endpoint{url: rawURL, queue: make(chan Event, queueSize)})
+	}
+	return n, nil
+}
+
+func validateURL(rawURL string) error {
+	u, err := url.Parse(rawURL)
+	if err != nil {
+		return fmt.Errorf("invalid webhook URL %q: %w", rawURL, err)
+	}
+	if u.Scheme != "http" && u.Scheme != "https" {
+		return fmt.Errorf("invalid webhook URL %q: scheme must be http or https", rawURL)
+	}
+	if u.Host == "" {
+		return fmt.Errorf("invalid webhook URL %q: host is missing", rawURL)
+	}
+	return nil
+}
+
+// Start starts delivering events to the webhook URLs.
+func (n *Notifier) Start() {
+	for _, e := range n.endpoints {
+		n.wg.Add(1)
+		go n.deliver(e)
+	}
+}
+
+// Stop stops delivering events, the ones not delivered yet are dropped.
+func (n *Notifier) Stop() {
+	n.stopOnce.Do(func() {
+		close(n.stop)
+	})
+	n.wg.Wait()
+}
+
+// Subscribe subscribes notifier to the events sent to webhooks.
+func (n *Notifier) Subscribe(bus eventbus.Subscriber) error {
+	if err := bus.Subscribe(connectionstate.AppTopicConnectionState, n.consumeConnectionStateEvent); err != nil {
+		return err
+	}
+	if err := bus.Subscribe(connectionstate.AppTopicConnectionPaymentFailed, n.consumePaymentFailedEvent); err != nil {
+		return err
+	}
+	if n.balanceLow == nil {
+		return nil
+	}
+	return bus.Subscribe(event.AppTopicBalanceChanged, n.consumeBalanceChangedEvent)
+}
+
+func (n *Notifier) consumeConnectionStateEvent(e connectionstate.AppEventConnectionState) {
+	n.enqueue(Event{
+		Type:             EventTypeConnectionState,
+		State:            string(e.State),
+		SessionID:        string(e.SessionInfo.SessionID),
+		ConsumerID:       e.SessionInfo.ConsumerID.Address,
+		ProviderID:       e.SessionInfo.Proposal.ProviderID,
+		DisconnectReason: e.SessionInfo.DisconnectReason,
+		Timestamp:        time.Now().UTC(),
+	})
+}
+
+func (n *Notifier) consumePaymentFailedEvent(e connectionstate.AppEventConnectionPaymentFailed) {
+	n.enqueue(Event{
+		Type:       EventTypePaymentFailed,
+		SessionID:  string(e.SessionInfo.SessionID),
+		ConsumerID: e.SessionInfo.ConsumerID.Address,
+		ProviderID: e.SessionInfo.Proposal.ProviderID,
+		Error:      e.Error,
+		Timestamp:  time.Now().UTC(),
+	})
+}
+
+func (n *Notifier) consumeBalanceChangedEvent(e event.AppEventBalanceChanged) {
+	if e.Current == nil || e.Current.Cmp(n.balanceLow) >= 0 {
+		return
+	}
+	// Only notify once the balance crosses the threshold, not on every change below it.
+	if e.Previous != nil && e.Previous.Cmp(n.balanceLow) < 0 {
+		return
+	}
+
+	n.enqueue(Event{
+		Type:       EventTypeBalanceLow,
+		ConsumerID: e.Identity.Address,
+		Balance:    e.Current.String(),
+		Timestamp:  time.Now().UTC(),
+	})
+}
+
+func (n *Notifier) enqueue(e Event) {
+	for _, ep := range n.endpoints {
+		select {
+		case ep.queue <- e:
+		default:
+			log.Warn().Msgf("Webhook %s queue is full, dropping %s event", ep.url, e.Type)
+		}
+	}
+}
+
+func (n *Notifier) deliver(ep *endpoint) {
+	defer n.wg.Done()
+
+	for {
+		select {
+		case <-n.stop:
+			return
+		case e := <-ep.queue:
+			n.send(ep.url, e)
+		}
+	}
+}
+
+func (n *Notifier) send(webhookURL string, e Event) {
+	body, err := json.Marshal(e)
+	if err != nil {
+		log.Error().Err(err).Msg("Could not marshal webhook event")
+		return
+	}
+
+	for attempt := 1; ; attempt++ {
+		err := n.post(webhookURL, body)
+		if err == nil {
+			return
+		}
+		if attempt >= n.attempts {
+			log.Warn().Err(err).Msgf("Failed to deliver %s event to webhook %s", e.Type, webhookURL)
+			return
+		}
+
+		select {
+		case <-n.stop:
+			return
+		case <-time.After(n.retryDelay * time.Duration(attempt)):
+		}
+	}
+}
+
+func (n *Notifier) post(webhookURL string, body []byte) error {
+	req, err := http.NewRequest(http.MethodPost, webhookURL, bytes.NewReader(body))
+	if err != nil {
+		return err
+	}
+	req.Header.Set("Content-Type", "application/json")
+	if n.secret != "" {
+		req.Header.Set(SignatureHeader, "sha256="+Sign(body, n.secret))
+	}
+
+	resp, err := n.client.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		return fmt.Errorf("unexpected response status %d", resp.StatusCode)
+	}
+	return nil
+}
+
+// Sign returns hex encoded HMAC-SHA256 of the body keyed with secret.
+func Sign(body []byte, secret string) string {
+	mac := hmac.New(sha256.New, []byte(secret))
+	mac.Write(body)
+	return hex.EncodeToString(mac.Sum(nil))
+}
@@ -0,0 +1,179 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package webhook
+
+import (
+	"encoding/json"
+	"io"
+	"math/big"
+	"net/http"
+	"net/http/httptest"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/mysteriumnetwork/node/core/connection/connectionstate"
+	"github.com/mysteriumnetwork/node/eventbus"
+	"github.com/mysteriumnetwork/node/identity"
+	"github.com/mysteriumnetwork/node/session/pingpong/event"
+)
+
+func TestNotifier_PostsSignedConnectionStateWithRetry(t *testing.T) {
+	var calls int
+	received := make(chan Event, 1)
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		calls++
+		if calls == 1 {
+			w.WriteHeader(http.StatusServiceUnavailable)
+			return
+		}
+		body, _ := io.ReadAll(r.Body)
+		assert.Equal(t, "sha256="+Sign(body, "secret"), r.Header.Get(SignatureHeader))
+		var e Event
+		assert.NoError(t, json.Unmarshal(body, &e))
+		received <- e
+	}))
+	defer server.Close()
+
+	notifier, err := NewNotifier([]string{server.URL}, "secret", nil, http.DefaultClient)
+	require.NoError(t, err)
+	notifier.retryDelay = time.Millisecond
+	notifier.Start()
+	defer notifier.Stop()
+
+	notifier.consumeConnectionStateEvent(connectionstate.AppEventConnectionState{
+		State: connectionstate.Connected,
+		SessionInfo: connectionstate.Status{
+			SessionID:  "session-1",
+			ConsumerID: identity.FromAddress("0x1"),
+		},
+	})
+
+	select {
+	case e := <-received:
+		assert.Equal(t, EventTypeConnectionState, e.Type)
+		assert.Equal(t, "Connected", e.State)
+		assert.Equal(t, "session-1", e.SessionID)
+		assert.Equal(t, "0x1", e.ConsumerID)
+	case <-time.After(time.Second):
+		t.Fatal("event was not delivered")
+	}
+	assert.Equal(t, 2, calls)
+}
+
+func TestNotifier_DeliversEventsInOrder(t *testing.T) {
+	var mu sync.Mutex
+	var calls int
+	var states []string
+	delivered := make(chan struct{}, 3)
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		mu.Lock()
+		defer mu.Unlock()
+		calls++
+		if calls == 1 {
+			// First event is delivered only on retry, the ones after it must wait.
+			w.WriteHeader(http.StatusServiceUnavailable)
+			return
+		}
+		var e Event
+		assert.NoError(t, json.NewDecoder(r.Body).Decode(&e))
+		states = append(states, e.State)
+		delivered <- struct{}{}
+	}))
+	defer server.Close()
+
+	notifier, err := NewNotifier([]string{server.URL}, "", nil, http.DefaultClient)
+	require.NoError(t, err)
+	notifier.retryDelay = 10 * time.Millisecond
+	notifier.Start()
+	defer notifier.Stop()
+
+	for _, state := range []connectionstate.State{connectionstate.Connecting, connectionstate.Connected, connectionstate.NotConnected} {
+		notifier.consumeConnectionStateEvent(connectionstate.AppEventConnectionState{State: state})
+	}
+
+	for i := 0; i < 3; i++ {
+		select {
+		case <-delivered:
+		case <-time.After(time.Second):
+			t.Fatal("events were not delivered")
+		}
+	}
+	mu.Lock()
+	defer mu.Unlock()
+	assert.Equal(t, []string{"Connecting", "Connected", "NotConnected"}, states)
+}
+
+func TestNotifier_GivesUpAfterAttempts(t *testing.T) {
+	var calls int
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		calls++
+		assert.Empty(t, r.Header.Get(SignatureHeader))
+		w.WriteHeader(http.StatusInternalServerError)
+	}))
+	defer server.Close()
+
+	notifier, err := NewNotifier([]string{server.URL}, "", nil, http.DefaultClient)
+	require.NoError(t, err)
+	notifier.retryDelay = time.Millisecond
+
+	notifier.send(server.URL, Event{Type: EventTypeConnectionState, State: "NotConnected"})
+
+	assert.Equal(t, 3, calls)
+}
+
+func TestNewNotifier_ValidatesURLs(t *testing.T) {
+	for _, rawURL := range []string{"ftp://example.com/hook", "file:///etc/passwd", "example.com/hook", "http://"} {
+		_, err := NewNotifier([]string{rawURL}, "", nil, http.DefaultClient)
+		assert.Error(t, err, rawURL)
+	}
+
+	_, err := NewNotifier([]string{"http://127.0.0.1:8080/hook", "https://example.com/hook"}, "", nil, http.DefaultClient)
+	assert.NoError(t, err)
+}
+
+func TestNotifier_SubscribesToPaymentAndBalanceEvents(t *testing.T) {
+	notifier, err := NewNotifier([]string{"https://example.com/hook"}, "", big.NewInt(100), http.DefaultClient)
+	require.NoError(t, err)
+	bus := eventbus.New()
+	require.NoError(t, notifier.Subscribe(bus))
+	queue := notifier.endpoints[0].queue
+
+	bus.Publish(connectionstate.AppTopicConnectionPaymentFailed, connectionstate.AppEventConnectionPaymentFailed{
+		SessionInfo: connectionstate.Status{SessionID: "session-1"},
+		Error:       "insufficient balance",
+	})
+	e := <-queue
+	assert.Equal(t, EventTypePaymentFailed, e.Type)
+	assert.Equal(t, "session-1", e.SessionID)
+	assert.Equal(t, "insufficient balance", e.Error)
+
+	consumer := identity.FromAddress("0x1")
+	bus.Publish(event.AppTopicBalanceChanged, event.AppEventBalanceChanged{Identity: consumer, Previous: big.NewInt(150), Current: big.NewInt(120)})
+	bus.Publish(event.AppTopicBalanceChanged, event.AppEventBalanceChanged{Identity: consumer, Previous: big.NewInt(120), Current: big.NewInt(90)})
+	bus.Publish(event.AppTopicBalanceChanged, event.AppEventBalanceChanged{Identity: consumer, Previous: big.NewInt(90), Current: big.NewInt(50)})
+
+	e = <-queue
+	assert.Equal(t, EventTypeBalanceLow, e.Type)
+	assert.Equal(t, "0x1", e.ConsumerID)
+	assert.Equal(t, "90", e.Balance)
+	assert.Empty(t, queue, "balance low is sent only when crossing the threshold")
+}
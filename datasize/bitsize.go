@@ -19,6 +19,9 @@ package datasize
 
 import (
 	"fmt"
+	"strconv"
+	"strings"
+	"unicode"
 )
 
 // BitSize represents data size in various units.
@@ -45,6 +48,47 @@ const (
 
 var units = []string{"B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"}
 
+// parseUnits maps case-insensitive unit suffixes to their sizes. Both decimal and binary units are accepted.
+var parseUnits = map[string]BitSize{
+	"":    B,
+	"B":   B,
+	"KB":  1000 * B,
+	"MB":  1000 * 1000 * B,
+	"GB":  1000 * 1000 * 1000 * B,
+	"TB":  1000 * 1000 * 1000 * 1000 * B,
+	"KIB": KiB,
+	"MIB": MiB,
+	"GIB": GiB,
+	"TIB": TiB,
+}
+
+// Parse parses human readable data size, e.g. "10GB" or "512MiB". Values without unit are treated as bytes.
+func Parse(s string) (BitSize, error) {
+	s = strings.TrimSpace(s)
+	if s == "" {
+		return 0, nil
+	}
+
+	i := strings.IndexFunc(s, func(r rune) bool {
+		return !unicode.IsDigit(r) && r != '.'
+	})
+	if i == -1 {
+		i = len(s)
+	}
+
+	value, err := strconv.ParseFloat(s[:i], 64)
+	if err != nil {
+		return 0, fmt.Errorf("invalid data size %q: %w", s, err)
+	}
+
+	unit, ok := parseUnits[strings.ToUpper(strings.TrimSpace(s[i:]))]
+	if !ok {
+		return 0, fmt.Errorf("unknown data size unit in %q", s)
+	}
+
+	return BitSize(value) * unit, nil
+}
+
 // FromBytes creates BitSize from bytes value.
 func FromBytes(bytes uint64) BitSize {
 	return BitSize(bytes * B.Bits())
@@ -91,3 +91,30 @@ func Test_String(t *testing.T) {
 		})
 	}
 }
+
+func TestParse(t *testing.T) {
+	table := []struct {
+		value         string
+		valueExpected uint64
+		err           bool
+	}{
+		{"", 0, false},
+		{"1024", 1024, false},
+		{"10GB", 10 * 1000 * 1000 * 1000, false},
+		{"1.5 KiB", 1536, false},
+		{"512mib", 512 * 1024 * 1024, false},
+		{"10XB", 0, true},
+		{"GB", 0, true},
+		{"-1GB", 0, true},
+	}
+
+	for _, tt := range table {
+		size, err := Parse(tt.value)
+		if tt.err {
+			assert.Error(t, err, tt.value)
+			continue
+		}
+		assert.NoError(t, err, tt.value)
+		assert.Equal(t, tt.valueExpected, size.Bytes(), tt.value)
+	}
+}
@@ -79,6 +79,18 @@ func (h *handler) SetDefault(address string) error {
 	return h.cache.StoreIdentity(id)
 }
 
+func (h *handler) GetDefault() (identity.Identity, error) {
+	id, err := h.cache.GetIdentity()
+	if err != nil {
+		return id, err
+	}
+	if !h.manager.HasIdentity(id.Address) {
+		return id, errors.New("default identity is not known")
+	}
+
+	return id, nil
+}
+
 func (h *handler) useExisting(address, passphrase string, chainID int64) (id identity.Identity, err error) {
 	log.Debug().Msg("Attempting to use existing identity")
 	id, err = h.manager.GetIdentity(address)
@@ -23,4 +23,5 @@ import "github.com/mysteriumnetwork/node/identity"
 type Handler interface {
 	UseOrCreate(address, passphrase string, chainID int64) (identity.Identity, error)
 	SetDefault(address string) error
+	GetDefault() (identity.Identity, error)
 }
@@ -221,10 +221,21 @@ func makeIPTablesRules(opts Options) (rules []iptables.Rule) {
 	rules = append(rules, iptables.AppendTo(chainForward).RuleSpec("--source", vpnNetwork, "--jump", "ACCEPT"))
 	rules = append(rules, iptables.AppendTo(chainForward).RuleSpec("--destination", vpnNetwork, "--jump", "ACCEPT"))
 
+	// TCP MSS clamping rules, so that large segments are not blackholed on paths with smaller MTU
+	if config.GetBool(config.FlagWireguardMSSClamp) {
+		for _, direction := range []string{"--source", "--destination"} {
+			rule = iptables.AppendTo(chainForward).RuleSpec(direction, vpnNetwork,
+				"--protocol", "tcp", "--tcp-flags", "SYN,RST", "SYN",
+				"--jump", "TCPMSS", "--clamp-mss-to-pmtu",
+				"--table", "mangle")
+			rules = append(rules, rule)
+		}
+	}
+
 	return rules
 }
 
-func iptablesExec(args ...string) error {
+var iptablesExec = func(args ...string) error {
 	args = append([]string{"/usr/sbin/iptables"}, args...)
 	if err := cmdutil.SudoExec(args...); err != nil {
 		return errors.Wrap(err, "error calling IPTables")
@@ -0,0 +1,84 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package nat
+
+import (
+	"net"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/mysteriumnetwork/node/config"
+)
+
+var iptablesTestOptions = Options{
+	VPNNetwork:    net.IPNet{IP: net.ParseIP("10.182.0.0").To4(), Mask: net.CIDRMask(24, 32)},
+	ProviderExtIP: net.ParseIP("1.2.3.4"),
+	DNSIP:         net.ParseIP("10.182.0.1"),
+}
+
+func mssClampRules(execs []string) []string {
+	var res []string
+	for _, args := range execs {
+		if strings.Contains(args, "TCPMSS") {
+			res = append(res, args)
+		}
+	}
+	return res
+}
+
+func Test_MakeIPTablesRules_MSSClampDisabledByDefault(t *testing.T) {
+	config.Current.SetDefault(config.FlagWireguardMSSClamp.Name, config.FlagWireguardMSSClamp.Value)
+
+	var applied []string
+	for _, rule := range makeIPTablesRules(iptablesTestOptions) {
+		applied = append(applied, strings.Join(rule.ApplyArgs(), " "))
+	}
+
+	assert.Empty(t, mssClampRules(applied))
+}
+
+func Test_ServiceIPTables_MSSClampRulesAreAppliedAndRemoved(t *testing.T) {
+	config.Current.SetUser(config.FlagWireguardMSSClamp.Name, true)
+	defer config.Current.RemoveUser(config.FlagWireguardMSSClamp.Name)
+
+	var execs []string
+	defaultExec := iptablesExec
+	iptablesExec = func(args ...string) error {
+		execs = append(execs, strings.Join(args, " "))
+		return nil
+	}
+	defer func() { iptablesExec = defaultExec }()
+
+	svc := &serviceIPTables{}
+	rules, err := svc.Setup(iptablesTestOptions)
+	assert.NoError(t, err)
+	assert.Equal(t, []string{
+		"-A FORWARD --source 10.182.0.0/24 --protocol tcp --tcp-flags SYN,RST SYN --jump TCPMSS --clamp-mss-to-pmtu --table mangle",
+		"-A FORWARD --destination 10.182.0.0/24 --protocol tcp --tcp-flags SYN,RST SYN --jump TCPMSS --clamp-mss-to-pmtu --table mangle",
+	}, mssClampRules(execs))
+
+	execs = nil
+	assert.NoError(t, svc.Del(rules))
+	assert.Equal(t, []string{
+		"-D FORWARD --source 10.182.0.0/24 --protocol tcp --tcp-flags SYN,RST SYN --jump TCPMSS --clamp-mss-to-pmtu --table mangle",
+		"-D FORWARD --destination 10.182.0.0/24 --protocol tcp --tcp-flags SYN,RST SYN --jump TCPMSS --clamp-mss-to-pmtu --table mangle",
+	}, mssClampRules(execs))
+	assert.Empty(t, svc.rules)
+}
@@ -43,6 +43,7 @@ type startConn func(conf wgcfg.DeviceConfig) (wg.ConnectionEndpoint, error)
 type Options struct {
 	DNSScriptDir     string
 	HandshakeTimeout time.Duration
+	MTU              int
 }
 
 // NewConnection returns new WireGuard connection.
@@ -146,6 +147,7 @@ func (c *Connection) start(ctx context.Context, start startConn, options connect
 	var conn wg.ConnectionEndpoint
 	conn, err = start(wgcfg.DeviceConfig{
 		IfaceName:    "", // Interface name will be generated by connection endpoint.
+		MTU:          c.opts.MTU,
 		Subnet:       config.Consumer.IPAddress,
 		PrivateKey:   c.privateKey,
 		ListenPort:   config.LocalPort,
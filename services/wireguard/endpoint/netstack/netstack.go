@@ -1046,6 +1046,106 @@ func (tnet *Net) DialContext(ctx context.Context, network, address string) (net.
 		return nil, &net.OpError{Op: "dial", Err: errNoSuitableAddress}
 	}
 
+	if matches[1] == "tcp" {
+		primaries, fallbacks := partitionByFamily(addrs)
+		if len(fallbacks) > 0 {
+			dial := func(ctx context.Context, addrs []netip.AddrPort) (net.Conn, error) {
+				return tnet.dialSerial(ctx, matches[1], addrs)
+			}
+			return dialParallel(ctx, dial, primaries, fallbacks, fallbackDelay)
+		}
+	}
+	return tnet.dialSerial(ctx, matches[1], addrs)
+}
+
+// fallbackDelay is how long dialing of the preferred address family gets before the other family joins the race (RFC 8305).
+const fallbackDelay = 300 * time.Millisecond
+
+// partitionByFamily splits addresses into IPv6 ones, which are preferred as RFC 8305 recommends, and IPv4 fallbacks.
+// If there are no IPv6 addresses, IPv4 ones are the primaries.
+func partitionByFamily(addrs []netip.AddrPort) (primaries, fallbacks []netip.AddrPort) {
+	for _, addr := range addrs {
+		if addr.Addr().Is6() {
+			primaries = append(primaries, addr)
+		} else {
+			fallbacks = append(fallbacks, addr)
+		}
+	}
+	if len(primaries) == 0 {
+		return fallbacks, nil
+	}
+	return primaries, fallbacks
+}
+
+// dialParallel races dialing of both address families, starting the fallback family after the given delay
+// or as soon as the primary one fails. The first established connection wins.
+func dialParallel(
+	ctx context.Context,
+	dial func(ctx context.Context, addrs []netip.AddrPort) (net.Conn, error),
+	primaries, fallbacks []netip.AddrPort,
+	delay time.Duration,
+) (net.Conn, error) {
+	type dialResult struct {
+		net.Conn
+		error
+		primary bool
+		done    bool
+	}
+	results := make(chan dialResult)
+	returned := make(chan struct{})
+	defer close(returned)
+
+	startRacer := func(ctx context.Context, primary bool) {
+		addrs := primaries
+		if !primary {
+			addrs = fallbacks
+		}
+		c, err := dial(ctx, addrs)
+		select {
+		case results <- dialResult{Conn: c, error: err, primary: primary, done: true}:
+		case <-returned:
+			if err == nil {
+				c.Close()
+			}
+		}
+	}
+
+	primaryCtx, primaryCancel := context.WithCancel(ctx)
+	defer primaryCancel()
+	go startRacer(primaryCtx, true)
+
+	fallbackTimer := time.NewTimer(delay)
+	defer fallbackTimer.Stop()
+
+	var primary, fallback dialResult
+	for {
+		select {
+		case <-fallbackTimer.C:
+			fallbackCtx, fallbackCancel := context.WithCancel(ctx)
+			defer fallbackCancel()
+			go startRacer(fallbackCtx, false)
+
+		case res := <-results:
+			if res.error == nil {
+				return res.Conn, nil
+			}
+			if res.primary {
+				primary = res
+			} else {
+				fallback = res
+			}
+			if primary.done && fallback.done {
+				return nil, primary.error
+			}
+			if res.primary && fallbackTimer.Stop() {
+				// Primary family failed before the delay elapsed, start the fallback right away.
+				fallbackTimer.Reset(0)
+			}
+		}
+	}
+}
+
+func (tnet *Net) dialSerial(ctx context.Context, proto string, addrs []netip.AddrPort) (net.Conn, error) {
 	var firstErr error
 	for i, addr := range addrs {
 		select {
@@ -1077,7 +1177,8 @@ func (tnet *Net) DialContext(ctx context.Context, network, address string) (net.
 		}
 
 		var c net.Conn
-		switch matches[1] {
+		var err error
+		switch proto {
 		case "tcp":
 			c, err = tnet.DialContextTCPAddrPort(dialCtx, addr)
 		case "udp":
@@ -0,0 +1,187 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package netstack
+
+import (
+	"context"
+	"errors"
+	"net"
+	"net/netip"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+var (
+	testAddr4  = netip.MustParseAddrPort("1.2.3.4:443")
+	testAddr4b = netip.MustParseAddrPort("5.6.7.8:443")
+	testAddr6  = netip.MustParseAddrPort("[2001:db8::1]:443")
+	testAddr6b = netip.MustParseAddrPort("[2001:db8::2]:443")
+)
+
+func Test_PartitionByFamily(t *testing.T) {
+	tests := []struct {
+		name      string
+		addrs     []netip.AddrPort
+		primaries []netip.AddrPort
+		fallbacks []netip.AddrPort
+	}{
+		{
+			name:      "IPv6 is preferred even if IPv4 resolved first",
+			addrs:     []netip.AddrPort{testAddr4, testAddr6, testAddr4b, testAddr6b},
+			primaries: []netip.AddrPort{testAddr6, testAddr6b},
+			fallbacks: []netip.AddrPort{testAddr4, testAddr4b},
+		},
+		{
+			name:      "IPv6 only",
+			addrs:     []netip.AddrPort{testAddr6, testAddr6b},
+			primaries: []netip.AddrPort{testAddr6, testAddr6b},
+		},
+		{
+			name:      "IPv4 only",
+			addrs:     []netip.AddrPort{testAddr4, testAddr4b},
+			primaries: []netip.AddrPort{testAddr4, testAddr4b},
+		},
+		{
+			name: "no addresses",
+		},
+	}
+	for _, test := range tests {
+		t.Run(test.name, func(t *testing.T) {
+			primaries, fallbacks := partitionByFamily(test.addrs)
+			assert.Equal(t, test.primaries, primaries)
+			assert.Equal(t, test.fallbacks, fallbacks)
+		})
+	}
+}
+
+// fakeDialer dials IPv6 and IPv4 addresses with the configured behaviour and records which families were tried.
+type fakeDialer struct {
+	mu     sync.Mutex
+	dialed []bool
+
+	dial6 func(ctx context.Context) (net.Conn, error)
+	dial4 func(ctx context.Context) (net.Conn, error)
+}
+
+func (d *fakeDialer) Dial(ctx context.Context, addrs []netip.AddrPort) (net.Conn, error) {
+	is6 := addrs[0].Addr().Is6()
+	d.mu.Lock()
+	d.dialed = append(d.dialed, is6)
+	d.mu.Unlock()
+
+	if is6 {
+		return d.dial6(ctx)
+	}
+	return d.dial4(ctx)
+}
+
+func (d *fakeDialer) families() []bool {
+	d.mu.Lock()
+	defer d.mu.Unlock()
+	return append([]bool(nil), d.dialed...)
+}
+
+func connected(_ context.Context) (net.Conn, error) {
+	c, _ := net.Pipe()
+	return c, nil
+}
+
+func failing(msg string) func(ctx context.Context) (net.Conn, error) {
+	return func(_ context.Context) (net.Conn, error) {
+		return nil, errors.New(msg)
+	}
+}
+
+func blackholed(ctx context.Context) (net.Conn, error) {
+	<-ctx.Done()
+	return nil, ctx.Err()
+}
+
+func Test_DialParallel(t *testing.T) {
+	const delay = 50 * time.Millisecond
+
+	tests := []struct {
+		name        string
+		dial6       func(ctx context.Context) (net.Conn, error)
+		dial4       func(ctx context.Context) (net.Conn, error)
+		delay       time.Duration
+		wantErr     string
+		minDuration time.Duration
+		maxDuration time.Duration
+		families    []bool
+	}{
+		{
+			name:        "primary connects before the fallback starts",
+			dial6:       connected,
+			dial4:       connected,
+			delay:       time.Minute,
+			maxDuration: time.Second,
+			families:    []bool{true},
+		},
+		{
+			name:        "fallback joins after the delay when primary hangs",
+			dial6:       blackholed,
+			dial4:       connected,
+			delay:       delay,
+			minDuration: delay,
+			maxDuration: time.Second,
+			families:    []bool{true, false},
+		},
+		{
+			name:        "fallback starts right away when primary fails",
+			dial6:       failing("ipv6 unreachable"),
+			dial4:       connected,
+			delay:       time.Minute,
+			maxDuration: time.Second,
+			families:    []bool{true, false},
+		},
+		{
+			name:        "both families fail",
+			dial6:       failing("ipv6 unreachable"),
+			dial4:       failing("ipv4 unreachable"),
+			delay:       delay,
+			wantErr:     "ipv6 unreachable",
+			maxDuration: time.Second,
+			families:    []bool{true, false},
+		},
+	}
+	for _, test := range tests {
+		t.Run(test.name, func(t *testing.T) {
+			dialer := &fakeDialer{dial6: test.dial6, dial4: test.dial4}
+
+			started := time.Now()
+			conn, err := dialParallel(context.Background(), dialer.Dial,
+				[]netip.AddrPort{testAddr6}, []netip.AddrPort{testAddr4}, test.delay)
+			elapsed := time.Since(started)
+
+			if test.wantErr != "" {
+				assert.EqualError(t, err, test.wantErr)
+			} else {
+				require.NoError(t, err)
+				conn.Close()
+			}
+			assert.GreaterOrEqual(t, elapsed, test.minDuration)
+			assert.Less(t, elapsed, test.maxDuration)
+			assert.Equal(t, test.families, dialer.families())
+		})
+	}
+}
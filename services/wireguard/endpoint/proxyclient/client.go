@@ -20,9 +20,14 @@ package proxyclient
 import (
 	"bufio"
 	"context"
+	"errors"
 	"fmt"
+	"io/fs"
+	"net"
 	"net/http"
 	"net/netip"
+	"os"
+	"path/filepath"
 	"strings"
 	"sync"
 	"time"
@@ -31,21 +36,27 @@ import (
 	"golang.zx2c4.com/wireguard/conn"
 	"golang.zx2c4.com/wireguard/device"
 
+	"github.com/mysteriumnetwork/node/config"
 	"github.com/mysteriumnetwork/node/services/wireguard/endpoint/netstack"
 	"github.com/mysteriumnetwork/node/services/wireguard/endpoint/userspace"
 	"github.com/mysteriumnetwork/node/services/wireguard/wgcfg"
 )
 
 type client struct {
-	mu         sync.Mutex
-	Device     *device.Device
-	proxyClose func() error
+	mu           sync.Mutex
+	Device       *device.Device
+	proxyClose   func() error
+	proxyHandler *proxyHandler
+	proxyPort    int
+	drainTimeout time.Duration
 }
 
 // New create new WireGuard client which serves requests via proxy.
 func New() (*client, error) {
 	log.Debug().Msg("Creating proxy wg client")
-	return &client{}, nil
+	return &client{
+		drainTimeout: config.GetDuration(config.FlagProxyDrainTimeout),
+	}, nil
 }
 
 func (c *client) ReConfigureDevice(config wgcfg.DeviceConfig) error {
@@ -64,7 +75,7 @@ func (c *client) ConfigureDevice(cfg wgcfg.DeviceConfig) error {
 	if err != nil {
 		return fmt.Errorf("could not parse DNS addr: %w", err)
 	}
-	tunnel, tnet, err := netstack.CreateNetTUN([]netip.Addr{localAddr}, []netip.Addr{dnsAddr}, device.DefaultMTU)
+	tunnel, tnet, err := netstack.CreateNetTUN([]netip.Addr{localAddr}, []netip.Addr{dnsAddr}, cfg.TunnelMTU())
 	if err != nil {
 		return fmt.Errorf("failed to create netstack device %s: %w", cfg.IfaceName, err)
 	}
@@ -82,14 +93,29 @@ func (c *client) ConfigureDevice(cfg wgcfg.DeviceConfig) error {
 	wgDevice.Up()
 
 	c.mu.Lock()
-	c.Device = wgDevice
+	handler := c.proxyHandler
+	proxyPort := c.proxyPort
 	c.mu.Unlock()
 
-	if err := c.Proxy(tnet, cfg.ProxyPort); err != nil {
+	// Keep the listener apps are configured against and only route new tunnels via the new device.
+	if handler != nil && proxyPort == cfg.ProxyPort {
+		log.Info().Msgf("Switching proxy server at :%d to the new device", proxyPort)
+		handler.setDialer(tnet)
+	} else if err := c.Proxy(tnet, cfg.ProxyPort); err != nil {
 		wgDevice.Close()
 		return err
 	}
 
+	// Swap the device only once the proxy uses it, so a failed reconfiguration leaves the previous one working.
+	c.mu.Lock()
+	oldDevice := c.Device
+	c.Device = wgDevice
+	c.mu.Unlock()
+
+	if oldDevice != nil {
+		c.closeDeviceLater(oldDevice)
+	}
+
 	return nil
 }
 
@@ -98,7 +124,15 @@ func (c *client) DestroyDevice(iface string) error {
 }
 
 func (c *client) PeerStats(iface string) (wgcfg.Stats, error) {
-	deviceState, err := userspace.ParseUserspaceDevice(c.Device.IpcGetOperation)
+	c.mu.Lock()
+	wgDevice := c.Device
+	c.mu.Unlock()
+
+	if wgDevice == nil {
+		return wgcfg.Stats{}, errors.New("device is not configured")
+	}
+
+	deviceState, err := userspace.ParseUserspaceDevice(wgDevice.IpcGetOperation)
 	if err != nil {
 		return wgcfg.Stats{}, fmt.Errorf("could not parse device state: %w", err)
 	}
@@ -120,43 +154,126 @@ func (c *client) Close() (err error) {
 
 	if c.proxyClose != nil {
 		c.proxyClose()
+		c.proxyClose = nil
+		c.proxyHandler = nil
 	}
 
+	c.proxyPort = 0
+
 	if c.Device != nil {
-		go func() {
-			time.Sleep(2 * time.Minute)
-			c.Device.Close()
-		}()
+		c.closeDeviceLater(c.Device)
+		c.Device = nil
 	}
 	return nil
 }
 
+// closeDeviceLater gives tunnels established through the device the drain timeout to finish before closing it.
+func (c *client) closeDeviceLater(d *device.Device) {
+	go func() {
+		time.Sleep(c.drainTimeout)
+		d.Close()
+	}()
+}
+
 func (c *client) Proxy(tnet *netstack.Net, proxyPort int) error {
+	handler := newProxyHandler(60*time.Second, tnet)
+	if size := config.GetInt(config.FlagProxyBufferSize); size > 0 {
+		handler.buffers = NewBufferPool(size)
+	}
+	handler.dialRetries = uint64(config.GetInt(config.FlagProxyDialRetries))
+	handler.dialBackoff = config.GetDuration(config.FlagProxyDialBackoff)
+	if size := config.GetInt(config.FlagProxyDialQueue); size > 0 {
+		handler.dialQueue = make(chan struct{}, size)
+	}
+	handler.connectTimeout = config.GetDuration(config.FlagProxyConnectTimeout)
+	if path := config.GetString(config.FlagProxyTrafficLog); path != "" {
+		ports := strings.Split(config.GetString(config.FlagProxyTrafficLogPorts), ",")
+		trafficLog, err := newTrafficLog(path, ports, config.GetInt(config.FlagProxyTrafficLogPayload))
+		if err != nil {
+			return err
+		}
+		log.Warn().Msgf("Traffic log is enabled, metadata of proxied traffic is written to %s. "+
+			"It reveals visited hosts and should only be used for troubleshooting", path)
+		if len(trafficLog.ports) == 0 {
+			log.Warn().Msgf("Traffic log has no ports set in %s, nothing will be logged", config.FlagProxyTrafficLogPorts.Name)
+		}
+		handler.trafficLog = trafficLog
+	}
+
+	listener, err := proxyListener(config.GetString(config.FlagProxySocketDir), proxyPort)
+	if err != nil {
+		if handler.trafficLog != nil {
+			handler.trafficLog.Close()
+		}
+		return fmt.Errorf("could not listen for proxy clients: %w", err)
+	}
+
 	c.mu.Lock()
 	defer c.mu.Unlock()
 
+	// The previous proxy is replaced only after the new one could listen, so it keeps serving if this fails.
+	if c.proxyClose != nil {
+		c.proxyClose()
+		c.proxyClose = nil
+		c.proxyHandler = nil
+	}
+
 	server := http.Server{
-		Addr:              fmt.Sprintf(":%d", proxyPort),
-		Handler:           newProxyHandler(60*time.Second, tnet),
+		Handler:           handler,
 		ReadTimeout:       0,
 		ReadHeaderTimeout: 0,
 		WriteTimeout:      0,
 		IdleTimeout:       0,
 	}
 
-	log.Info().Msgf("Starting proxy server at :%d ...", proxyPort)
+	log.Info().Msgf("Starting proxy server at %s ...", listener.Addr())
+	c.proxyHandler = handler
+	c.proxyPort = proxyPort
+	drainTimeout := c.drainTimeout
 	c.proxyClose = func() error {
-		ctx, cancel := context.WithTimeout(context.Background(), 20*time.Second)
+		ctx, cancel := context.WithTimeout(context.Background(), drainTimeout)
 		defer cancel()
 		server.Shutdown(ctx)
 
+		if aborted := handler.drain(ctx); aborted > 0 {
+			log.Warn().Msgf("Proxy drain timeout of %s reached, %d in-flight tunnel(s) were cut", drainTimeout, aborted)
+		} else {
+			log.Info().Msg("Proxy server drained all in-flight tunnels")
+		}
+
+		if handler.trafficLog != nil {
+			handler.trafficLog.Close()
+		}
+
 		return server.Close()
 	}
 
 	go func() {
-		err := server.ListenAndServe()
+		err := server.Serve(listener)
 		log.Error().Err(err).Msg("Shutting down proxy server...")
 	}()
 
 	return nil
 }
+
+// proxyListener listens on the proxy TCP port or, if socket directory is given, on a unix socket named after the port.
+func proxyListener(socketDir string, proxyPort int) (net.Listener, error) {
+	if socketDir == "" {
+		return net.Listen("tcp", fmt.Sprintf(":%d", proxyPort))
+	}
+
+	path := filepath.Join(socketDir, fmt.Sprintf("proxy-%d.sock", proxyPort))
+	if err := os.Remove(path); err != nil && !errors.Is(err, fs.ErrNotExist) {
+		return nil, fmt.Errorf("could not remove stale socket %s: %w", path, err)
+	}
+
+	listener, err := net.Listen("unix", path)
+	if err != nil {
+		return nil, err
+	}
+	if err := os.Chmod(path, 0600); err != nil {
+		listener.Close()
+		return nil, fmt.Errorf("could not restrict socket permissions: %w", err)
+	}
+	return listener, nil
+}
@@ -19,10 +19,14 @@ package proxyclient
 
 import (
 	"net"
+	"os"
+	"path/filepath"
 	"testing"
 
+	"github.com/mysteriumnetwork/node/services/wireguard/key"
 	"github.com/mysteriumnetwork/node/services/wireguard/wgcfg"
 	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
 )
 
 func Test_ConfigureDevice_ConfigureErrors(t *testing.T) {
@@ -63,3 +67,83 @@ func Test_ConfigureDevice_ConfigureErrors(t *testing.T) {
 		})
 	}
 }
+
+func Test_ProxyListener_UnixSocket(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "proxy-10000.sock")
+	// Stale socket from a previous run must not prevent listening.
+	require.NoError(t, os.WriteFile(path, nil, 0600))
+
+	listener, err := proxyListener(dir, 10000)
+	require.NoError(t, err)
+	defer listener.Close()
+
+	info, err := os.Stat(path)
+	require.NoError(t, err)
+	assert.Equal(t, os.FileMode(0600), info.Mode().Perm())
+
+	go func() {
+		conn, err := listener.Accept()
+		if err == nil {
+			conn.Close()
+		}
+	}()
+
+	conn, err := net.Dial("unix", path)
+	require.NoError(t, err)
+	conn.Close()
+}
+
+func Test_ConfigureDevice_KeepsPreviousDeviceWhenProxyFails(t *testing.T) {
+	client, err := New()
+	require.NoError(t, err)
+	defer client.Close()
+
+	free, err := net.Listen("tcp", ":0")
+	require.NoError(t, err)
+	freePort := free.Addr().(*net.TCPAddr).Port
+	require.NoError(t, free.Close())
+
+	busy, err := net.Listen("tcp", ":0")
+	require.NoError(t, err)
+	defer busy.Close()
+	busyPort := busy.Addr().(*net.TCPAddr).Port
+
+	require.NoError(t, client.ConfigureDevice(testDeviceConfig(t, freePort)))
+	device := client.Device
+
+	err = client.ConfigureDevice(testDeviceConfig(t, busyPort))
+	assert.ErrorContains(t, err, "could not listen for proxy clients")
+	assert.Same(t, device, client.Device)
+	assert.Equal(t, freePort, client.proxyPort)
+	assert.NotNil(t, client.proxyHandler)
+
+	require.NoError(t, client.Close())
+	assert.Nil(t, client.Device)
+	assert.Nil(t, client.proxyHandler)
+	assert.Zero(t, client.proxyPort)
+
+	_, err = client.PeerStats("wg-test")
+	assert.EqualError(t, err, "device is not configured")
+}
+
+func testDeviceConfig(t *testing.T, proxyPort int) wgcfg.DeviceConfig {
+	privateKey, err := key.GeneratePrivateKey()
+	require.NoError(t, err)
+	peerKey, err := key.GeneratePrivateKey()
+	require.NoError(t, err)
+	peerPublicKey, err := key.PrivateKeyToPublicKey(peerKey)
+	require.NoError(t, err)
+
+	return wgcfg.DeviceConfig{
+		IfaceName:  "wg-test",
+		Subnet:     net.IPNet{IP: net.ParseIP("10.0.182.2"), Mask: net.IPv4Mask(255, 255, 255, 0)},
+		PrivateKey: privateKey,
+		DNS:        []string{"10.0.182.1"},
+		Peer: wgcfg.Peer{
+			PublicKey:  peerPublicKey,
+			AllowedIPs: []string{"0.0.0.0/0"},
+		},
+		ProxyPort: proxyPort,
+	}
+}
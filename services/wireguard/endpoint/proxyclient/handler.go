@@ -19,47 +19,215 @@ package proxyclient
 
 import (
 	"context"
+	"errors"
 	"fmt"
+	"net"
 	"net/http"
+	"net/url"
 	"strings"
 	"sync"
 	"time"
 
+	"github.com/cenkalti/backoff/v4"
 	"github.com/rs/zerolog/log"
 	"golang.org/x/net/proxy"
 )
 
+// errDialQueueFull is returned when too many outbound dials are already in progress.
+var errDialQueueFull = errors.New("dial queue is full")
+
 type proxyHandler struct {
-	timeout       time.Duration
-	httptransport http.RoundTripper
-	outbound      map[string]string
-	outboundMux   sync.RWMutex
-	dialer        proxy.ContextDialer
+	timeout        time.Duration
+	connectTimeout time.Duration
+	httptransport  *http.Transport
+	outbound       map[string]string
+	outboundMux    sync.RWMutex
+	dialer         proxy.ContextDialer
+	dialerMux      sync.RWMutex
+	trafficLog     *trafficLog
+	buffers        *BufferPool
+	dialRetries    uint64
+	dialBackoff    time.Duration
+	dialQueue      chan struct{}
+
+	// Hijacked tunnels are not tracked by http.Server, so they are counted here
+	// and aborted through tunnelsCtx once the drain deadline passes.
+	tunnelsCtx     context.Context
+	abortTunnels   context.CancelFunc
+	tunnels        int
+	tunnelsMux     sync.Mutex
+	tunnelsDrained chan struct{}
 }
 
 func newProxyHandler(timeout time.Duration, dialer proxy.ContextDialer) *proxyHandler {
-	httptransport := &http.Transport{
-		DialContext: dialer.DialContext,
+	tunnelsCtx, abortTunnels := context.WithCancel(context.Background())
+	s := &proxyHandler{
+		timeout:      timeout,
+		outbound:     make(map[string]string),
+		dialer:       dialer,
+		tunnelsCtx:   tunnelsCtx,
+		abortTunnels: abortTunnels,
+		buffers:      NewBufferPool(DefaultCopyBufferSize),
+	}
+	s.httptransport = s.newTransport(dialer)
+	return s
+}
+
+func (s *proxyHandler) newTransport(dialer proxy.ContextDialer) *http.Transport {
+	return &http.Transport{
+		DialContext: func(ctx context.Context, network, addr string) (net.Conn, error) {
+			return s.dialOnce(ctx, dialer, network, addr)
+		},
+	}
+}
+
+// setDialer switches new tunnels and requests to the given dialer.
+// Tunnels which are already established keep using the previous one.
+func (s *proxyHandler) setDialer(dialer proxy.ContextDialer) {
+	s.dialerMux.Lock()
+	old := s.httptransport
+	s.dialer = dialer
+	s.httptransport = s.newTransport(dialer)
+	s.dialerMux.Unlock()
+
+	old.CloseIdleConnections()
+}
+
+func (s *proxyHandler) currentDialer() (proxy.ContextDialer, http.RoundTripper) {
+	s.dialerMux.RLock()
+	defer s.dialerMux.RUnlock()
+
+	return s.dialer, s.httptransport
+}
+
+// dial connects to the target, retrying with backoff so transient provider
+// hiccups are not surfaced as connection resets to local apps.
+// Dials waiting for a retry hold a slot in the dial queue, so a flaky provider
+// can not pile up an unbounded number of them.
+func (s *proxyHandler) dial(ctx context.Context, target string) (net.Conn, error) {
+	if s.dialQueue != nil {
+		select {
+		case s.dialQueue <- struct{}{}:
+			defer func() { <-s.dialQueue }()
+		default:
+			return nil, errDialQueueFull
+		}
+	}
+
+	dialer, _ := s.currentDialer()
+
+	eback := backoff.NewExponentialBackOff()
+	eback.InitialInterval = s.dialBackoff
+	eback.MaxElapsedTime = 0
+	boff := backoff.WithContext(backoff.WithMaxRetries(eback, s.dialRetries), ctx)
+
+	var conn net.Conn
+	err := backoff.Retry(func() (err error) {
+		conn, err = s.dialOnce(ctx, dialer, "tcp", target)
+		if err != nil {
+			log.Debug().Err(err).Msgf("Failed to dial %s", target)
+		}
+		return err
+	}, boff)
+	return conn, err
+}
+
+// dialOnce makes a single connection attempt, bounded by the connect timeout
+// so blackholed addresses fail fast instead of waiting for the OS timeout.
+func (s *proxyHandler) dialOnce(ctx context.Context, dialer proxy.ContextDialer, network, addr string) (net.Conn, error) {
+	if s.connectTimeout > 0 {
+		var cancel context.CancelFunc
+		ctx, cancel = context.WithTimeout(ctx, s.connectTimeout)
+		defer cancel()
+	}
+	return dialer.DialContext(ctx, network, addr)
+}
+
+func (s *proxyHandler) tunnelStarted() {
+	s.tunnelsMux.Lock()
+	defer s.tunnelsMux.Unlock()
+
+	s.tunnels++
+}
+
+func (s *proxyHandler) tunnelFinished() {
+	s.tunnelsMux.Lock()
+	defer s.tunnelsMux.Unlock()
+
+	s.tunnels--
+	if s.tunnels == 0 && s.tunnelsDrained != nil {
+		close(s.tunnelsDrained)
+		s.tunnelsDrained = nil
 	}
-	return &proxyHandler{
-		timeout:       timeout,
-		httptransport: httptransport,
-		outbound:      make(map[string]string),
-		dialer:        dialer,
+}
+
+// drain waits for in-flight tunnels to finish until ctx is done and aborts the remaining ones.
+// It returns the number of aborted tunnels.
+func (s *proxyHandler) drain(ctx context.Context) int {
+	s.tunnelsMux.Lock()
+	if s.tunnels == 0 {
+		s.tunnelsMux.Unlock()
+		return 0
+	}
+	drained := make(chan struct{})
+	s.tunnelsDrained = drained
+	s.tunnelsMux.Unlock()
+
+	select {
+	case <-drained:
+		return 0
+	case <-ctx.Done():
 	}
+
+	s.tunnelsMux.Lock()
+	aborted := s.tunnels
+	s.tunnelsMux.Unlock()
+
+	s.abortTunnels()
+	return aborted
 }
 
 func (s *proxyHandler) handleTunnel(wr http.ResponseWriter, req *http.Request) {
-	ctx, cancel := context.WithTimeout(req.Context(), s.timeout)
+	s.tunnelStarted()
+	defer s.tunnelFinished()
+
+	tunnelCtx, abort := context.WithCancel(req.Context())
+	defer abort()
+	stop := context.AfterFunc(s.tunnelsCtx, abort)
+	defer stop()
+
+	ctx, cancel := context.WithTimeout(tunnelCtx, s.timeout)
 	defer cancel()
 
-	conn, err := s.dialer.DialContext(ctx, "tcp", req.RequestURI)
+	started := time.Now()
+	logged := s.trafficLog != nil && s.trafficLog.allowed(req.RequestURI)
+
+	conn, err := s.dial(ctx, req.RequestURI)
 	if err != nil {
 		log.Error().Err(err).Msg("Can't satisfy CONNECT request")
-		http.Error(wr, "Can't satisfy CONNECT request", http.StatusBadGateway)
+		status := http.StatusBadGateway
+		if errors.Is(err, errDialQueueFull) {
+			status = http.StatusServiceUnavailable
+		}
+		http.Error(wr, "Can't satisfy CONNECT request", status)
+		if logged {
+			s.trafficLog.record(newTrafficLogRecord(req, req.RequestURI, started, err))
+		}
 		return
 	}
 
+	var closedBy string
+	if logged {
+		loggedConn := s.trafficLog.wrap(conn)
+		conn = loggedConn
+		defer func() {
+			rec := newTrafficLogRecord(req, req.RequestURI, started, nil)
+			loggedConn.fill(&rec)
+			rec.ClosedBy = closedBy
+			s.trafficLog.record(rec)
+		}()
+	}
+
 	localAddr := conn.LocalAddr().String()
 	s.outboundMux.Lock()
 	s.outbound[localAddr] = req.RemoteAddr
@@ -84,12 +252,12 @@ func (s *proxyHandler) handleTunnel(wr http.ResponseWriter, req *http.Request) {
 		// Inform client connection is built
 		fmt.Fprintf(localconn, "HTTP/%d.%d 200 OK\r\n\r\n", req.ProtoMajor, req.ProtoMinor)
 
-		proxyHTTP1(req.Context(), localconn, conn)
+		closedBy = proxyHTTP1(tunnelCtx, s.buffers, localconn, conn)
 	} else if req.ProtoMajor == 2 {
 		wr.Header()["Date"] = nil
 		wr.WriteHeader(http.StatusOK)
 		flush(wr)
-		proxyHTTP2(req.Context(), req.Body, wr, conn)
+		proxyHTTP2(tunnelCtx, s.buffers, req.Body, wr, conn)
 	} else {
 		log.Error().Msgf("Unsupported protocol version: %s", req.Proto)
 		http.Error(wr, "Unsupported protocol version.", http.StatusBadRequest)
@@ -103,19 +271,49 @@ func (s *proxyHandler) handleRequest(wr http.ResponseWriter, req *http.Request)
 		req.URL.Scheme = "http" // We can't access :scheme pseudo-header, so assume http
 		req.URL.Host = req.Host
 	}
-	resp, err := s.httptransport.RoundTrip(req)
+	started := time.Now()
+	target := requestTarget(req.URL)
+	logged := s.trafficLog != nil && s.trafficLog.allowed(target)
+
+	var reqBody *loggedBody
+	if logged && req.Body != nil {
+		reqBody = s.trafficLog.wrapBody(req.Body)
+		req.Body = reqBody
+	}
+
+	_, transport := s.currentDialer()
+	resp, err := transport.RoundTrip(req)
 	if err != nil {
 		log.Error().Err(err).Msg("HTTP fetch error")
 		http.Error(wr, "Server Error", http.StatusInternalServerError)
+		if logged {
+			s.trafficLog.record(newTrafficLogRecord(req, target, started, err))
+		}
 		return
 	}
 	defer resp.Body.Close()
 
+	var respBody *loggedBody
+	if logged {
+		respBody = s.trafficLog.wrapBody(resp.Body)
+		resp.Body = respBody
+	}
+
 	delHopHeaders(resp.Header)
 	copyHeader(wr.Header(), resp.Header)
 	wr.WriteHeader(resp.StatusCode)
 	flush(wr)
-	copyBody(wr, resp.Body)
+	copyBody(s.buffers, wr, resp.Body)
+
+	if logged {
+		rec := newTrafficLogRecord(req, target, started, nil)
+		rec.Status = resp.StatusCode
+		if reqBody != nil {
+			rec.BytesSent, rec.PayloadSent = reqBody.get()
+		}
+		rec.BytesReceived, rec.PayloadReceived = respBody.get()
+		s.trafficLog.record(rec)
+	}
 }
 
 func (s *proxyHandler) isLoopback(req *http.Request) (string, bool) {
@@ -147,3 +345,24 @@ func (s *proxyHandler) ServeHTTP(wr http.ResponseWriter, req *http.Request) {
 		s.handleRequest(wr, req)
 	}
 }
+
+func newTrafficLogRecord(req *http.Request, target string, started time.Time, err error) trafficLogRecord {
+	rec := trafficLogRecord{
+		Time:     started,
+		Client:   req.RemoteAddr,
+		Method:   req.Method,
+		Target:   target,
+		Duration: time.Since(started).String(),
+	}
+	if err != nil {
+		rec.Error = err.Error()
+	}
+	return rec
+}
+
+func requestTarget(u *url.URL) string {
+	if u.Port() != "" {
+		return u.Host
+	}
+	return net.JoinHostPort(u.Hostname(), "80")
+}
@@ -0,0 +1,145 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package proxyclient
+
+import (
+	"context"
+	"errors"
+	"net"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func Test_Drain_WaitsForInFlightTunnels(t *testing.T) {
+	handler := newProxyHandler(time.Second, &net.Dialer{})
+	handler.tunnelStarted()
+
+	go func() {
+		time.Sleep(10 * time.Millisecond)
+		handler.tunnelFinished()
+	}()
+
+	ctx, cancel := context.WithTimeout(context.Background(), time.Second)
+	defer cancel()
+
+	assert.Equal(t, 0, handler.drain(ctx))
+	assert.NoError(t, handler.tunnelsCtx.Err())
+}
+
+func Test_Drain_AbortsTunnelsAfterDeadline(t *testing.T) {
+	handler := newProxyHandler(time.Second, &net.Dialer{})
+	handler.tunnelStarted()
+	handler.tunnelStarted()
+
+	ctx, cancel := context.WithTimeout(context.Background(), 10*time.Millisecond)
+	defer cancel()
+
+	assert.Equal(t, 2, handler.drain(ctx))
+	assert.Error(t, handler.tunnelsCtx.Err())
+}
+
+func Test_SetDialer_SwitchesNewTunnels(t *testing.T) {
+	oldDialer := &net.Dialer{}
+	newDialer := &net.Dialer{Timeout: time.Second}
+	handler := newProxyHandler(time.Second, oldDialer)
+
+	handler.setDialer(newDialer)
+
+	dialer, transport := handler.currentDialer()
+	assert.Same(t, newDialer, dialer)
+	assert.NotNil(t, transport)
+}
+
+func Test_Dial_RetriesFailedDials(t *testing.T) {
+	dialer := &flakyDialer{failures: 2}
+	handler := newProxyHandler(time.Second, dialer)
+	handler.dialRetries = 2
+	handler.dialBackoff = time.Millisecond
+
+	conn, err := handler.dial(context.Background(), "example.com:443")
+
+	assert.NoError(t, err)
+	assert.NotNil(t, conn)
+	assert.Equal(t, 3, dialer.attempts)
+}
+
+func Test_Dial_GivesUpAfterRetries(t *testing.T) {
+	dialer := &flakyDialer{failures: 5}
+	handler := newProxyHandler(time.Second, dialer)
+	handler.dialRetries = 1
+	handler.dialBackoff = time.Millisecond
+
+	_, err := handler.dial(context.Background(), "example.com:443")
+
+	assert.Error(t, err)
+	assert.Equal(t, 2, dialer.attempts)
+}
+
+func Test_Dial_FailsFastOnBlackholedTarget(t *testing.T) {
+	handler := newProxyHandler(time.Minute, blackholeDialer{})
+	handler.connectTimeout = 20 * time.Millisecond
+
+	started := time.Now()
+	_, err := handler.dial(context.Background(), "example.com:443")
+
+	assert.ErrorIs(t, err, context.DeadlineExceeded)
+	assert.Less(t, time.Since(started), time.Second)
+}
+
+func Test_Dial_RejectsWhenQueueIsFull(t *testing.T) {
+	handler := newProxyHandler(time.Minute, blackholeDialer{})
+	handler.dialQueue = make(chan struct{}, 1)
+
+	ctx, cancel := context.WithCancel(context.Background())
+	done := make(chan struct{})
+	go func() {
+		defer close(done)
+		_, _ = handler.dial(ctx, "example.com:443")
+	}()
+	assert.Eventually(t, func() bool { return len(handler.dialQueue) == 1 }, time.Second, time.Millisecond)
+
+	_, err := handler.dial(context.Background(), "example.com:443")
+	assert.ErrorIs(t, err, errDialQueueFull)
+
+	cancel()
+	<-done
+	assert.Len(t, handler.dialQueue, 0)
+}
+
+type flakyDialer struct {
+	failures int
+	attempts int
+}
+
+func (d *flakyDialer) DialContext(_ context.Context, _, _ string) (net.Conn, error) {
+	d.attempts++
+	if d.attempts <= d.failures {
+		return nil, errors.New("connection refused")
+	}
+	conn, _ := net.Pipe()
+	return conn, nil
+}
+
+type blackholeDialer struct{}
+
+func (blackholeDialer) DialContext(ctx context.Context, _, _ string) (net.Conn, error) {
+	<-ctx.Done()
+	return nil, ctx.Err()
+}
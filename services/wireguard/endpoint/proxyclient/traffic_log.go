@@ -0,0 +1,200 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package proxyclient
+
+import (
+	"encoding/json"
+	"fmt"
+	"io"
+	"net"
+	"os"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/rs/zerolog/log"
+)
+
+// trafficLog writes metadata of proxied traffic as JSON lines to a file for troubleshooting broken sessions.
+type trafficLog struct {
+	mu           sync.Mutex
+	file         *os.File
+	enc          *json.Encoder
+	closed       bool
+	ports        map[string]struct{}
+	payloadLimit int
+}
+
+// trafficLogRecord is a single line of the traffic log.
+type trafficLogRecord struct {
+	Time            time.Time `json:"time"`
+	Client          string    `json:"client"`
+	Method          string    `json:"method"`
+	Target          string    `json:"target"`
+	Status          int       `json:"status,omitempty"`
+	Duration        string    `json:"duration"`
+	BytesSent       int64     `json:"bytes_sent"`
+	BytesReceived   int64     `json:"bytes_received"`
+	Error           string    `json:"error,omitempty"`
+	ClosedBy        string    `json:"closed_by,omitempty"`
+	PayloadSent     []byte    `json:"payload_sent,omitempty"`
+	PayloadReceived []byte    `json:"payload_received,omitempty"`
+}
+
+// newTrafficLog opens the traffic log file. Only traffic to the given destination ports is logged,
+// nothing is logged if none are given. Up to payloadLimit bytes of payload are kept per direction.
+func newTrafficLog(path string, ports []string, payloadLimit int) (*trafficLog, error) {
+	file, err := os.OpenFile(path, os.O_CREATE|os.O_WRONLY|os.O_APPEND, 0600)
+	if err != nil {
+		return nil, fmt.Errorf("could not open traffic log file: %w", err)
+	}
+
+	allowed := make(map[string]struct{})
+	for _, port := range ports {
+		if port = strings.TrimSpace(port); port != "" {
+			allowed[port] = struct{}{}
+		}
+	}
+
+	return &trafficLog{
+		file:         file,
+		enc:          json.NewEncoder(file),
+		ports:        allowed,
+		payloadLimit: payloadLimit,
+	}, nil
+}
+
+func (tl *trafficLog) allowed(target string) bool {
+	_, port, err := net.SplitHostPort(target)
+	if err != nil {
+		return false
+	}
+	_, ok := tl.ports[port]
+	return ok
+}
+
+func (tl *trafficLog) wrap(conn net.Conn) *loggedConn {
+	return &loggedConn{
+		Conn:     conn,
+		sent:     counter{limit: tl.payloadLimit},
+		received: counter{limit: tl.payloadLimit},
+	}
+}
+
+func (tl *trafficLog) wrapBody(body io.ReadCloser) *loggedBody {
+	return &loggedBody{
+		ReadCloser: body,
+		counter:    counter{limit: tl.payloadLimit},
+	}
+}
+
+// record writes the record, unless the log was already closed by proxy shutdown.
+func (tl *trafficLog) record(rec trafficLogRecord) {
+	tl.mu.Lock()
+	defer tl.mu.Unlock()
+
+	if tl.closed {
+		return
+	}
+	if err := tl.enc.Encode(rec); err != nil {
+		log.Warn().Err(err).Msg("Failed to write traffic log record")
+	}
+}
+
+// Close closes the traffic log file, records of still running tunnels are dropped afterwards.
+func (tl *trafficLog) Close() error {
+	tl.mu.Lock()
+	defer tl.mu.Unlock()
+
+	if tl.closed {
+		return nil
+	}
+	tl.closed = true
+	return tl.file.Close()
+}
+
+// counter counts bytes passing in one direction and keeps the beginning of the payload.
+type counter struct {
+	mu      sync.Mutex
+	limit   int
+	bytes   int64
+	payload []byte
+}
+
+func (c *counter) add(b []byte) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	c.bytes += int64(len(b))
+	if free := c.limit - len(c.payload); free > 0 {
+		if len(b) > free {
+			b = b[:free]
+		}
+		c.payload = append(c.payload, b...)
+	}
+}
+
+func (c *counter) get() (int64, []byte) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	return c.bytes, c.payload
+}
+
+// loggedConn counts bytes passing through the outbound connection of a tunnel.
+type loggedConn struct {
+	net.Conn
+	sent     counter
+	received counter
+}
+
+func (c *loggedConn) Read(b []byte) (int, error) {
+	n, err := c.Conn.Read(b)
+	c.received.add(b[:n])
+	return n, err
+}
+
+func (c *loggedConn) Write(b []byte) (int, error) {
+	n, err := c.Conn.Write(b)
+	c.sent.add(b[:n])
+	return n, err
+}
+
+func (c *loggedConn) CloseWrite() error {
+	if cw, ok := c.Conn.(closeWriter); ok {
+		return cw.CloseWrite()
+	}
+	return c.Conn.Close()
+}
+
+func (c *loggedConn) fill(rec *trafficLogRecord) {
+	rec.BytesSent, rec.PayloadSent = c.sent.get()
+	rec.BytesReceived, rec.PayloadReceived = c.received.get()
+}
+
+// loggedBody counts bytes of a plain HTTP request or response body.
+type loggedBody struct {
+	io.ReadCloser
+	counter
+}
+
+func (b *loggedBody) Read(p []byte) (int, error) {
+	n, err := b.ReadCloser.Read(p)
+	b.counter.add(p[:n])
+	return n, err
+}
@@ -0,0 +1,115 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package proxyclient
+
+import (
+	"encoding/json"
+	"io"
+	"net"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func Test_TrafficLog_AllowsOnlyListedPorts(t *testing.T) {
+	trafficLog, err := newTrafficLog(filepath.Join(t.TempDir(), "traffic.jsonl"), []string{"443", " 8080"}, 0)
+	require.NoError(t, err)
+	defer trafficLog.Close()
+
+	assert.True(t, trafficLog.allowed("example.com:443"))
+	assert.True(t, trafficLog.allowed("example.com:8080"))
+	assert.False(t, trafficLog.allowed("example.com:80"))
+	assert.False(t, trafficLog.allowed("example.com"))
+}
+
+func Test_TrafficLog_AllowsNoPortsWhenEmpty(t *testing.T) {
+	trafficLog, err := newTrafficLog(filepath.Join(t.TempDir(), "traffic.jsonl"), []string{""}, 0)
+	require.NoError(t, err)
+	defer trafficLog.Close()
+
+	assert.False(t, trafficLog.allowed("example.com:80"))
+	assert.False(t, trafficLog.allowed("example.com:443"))
+}
+
+func Test_TrafficLog_RecordsCappedPayload(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "traffic.jsonl")
+	trafficLog, err := newTrafficLog(path, nil, 4)
+	require.NoError(t, err)
+
+	local, remote := net.Pipe()
+	conn := trafficLog.wrap(local)
+	go func() {
+		buf := make([]byte, 16)
+		n, _ := remote.Read(buf)
+		remote.Write(buf[:n])
+	}()
+
+	_, err = conn.Write([]byte("hello"))
+	require.NoError(t, err)
+	buf := make([]byte, 16)
+	_, err = conn.Read(buf)
+	require.NoError(t, err)
+
+	rec := trafficLogRecord{Target: "example.com:443"}
+	conn.fill(&rec)
+	trafficLog.record(rec)
+	require.NoError(t, trafficLog.Close())
+
+	data, err := os.ReadFile(path)
+	require.NoError(t, err)
+	var written trafficLogRecord
+	require.NoError(t, json.Unmarshal(data, &written))
+	assert.Equal(t, "example.com:443", written.Target)
+	assert.Equal(t, int64(5), written.BytesSent)
+	assert.Equal(t, int64(5), written.BytesReceived)
+	assert.Equal(t, []byte("hell"), written.PayloadSent)
+	assert.Equal(t, []byte("hell"), written.PayloadReceived)
+}
+
+func Test_TrafficLog_CountsBodyBytes(t *testing.T) {
+	trafficLog, err := newTrafficLog(filepath.Join(t.TempDir(), "traffic.jsonl"), nil, 3)
+	require.NoError(t, err)
+	defer trafficLog.Close()
+
+	body := trafficLog.wrapBody(io.NopCloser(strings.NewReader("response body")))
+	data, err := io.ReadAll(body)
+	require.NoError(t, err)
+	assert.Equal(t, "response body", string(data))
+
+	bytes, payload := body.get()
+	assert.Equal(t, int64(13), bytes)
+	assert.Equal(t, []byte("res"), payload)
+}
+
+func Test_TrafficLog_DropsRecordsAfterClose(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "traffic.jsonl")
+	trafficLog, err := newTrafficLog(path, nil, 0)
+	require.NoError(t, err)
+
+	require.NoError(t, trafficLog.Close())
+	trafficLog.record(trafficLogRecord{Target: "example.com:443"})
+	require.NoError(t, trafficLog.Close())
+
+	data, err := os.ReadFile(path)
+	require.NoError(t, err)
+	assert.Empty(t, data)
+}
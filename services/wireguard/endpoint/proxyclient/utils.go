@@ -26,33 +26,53 @@ import (
 	"net/http"
 	"sync"
 	"time"
+
+	"github.com/rs/zerolog/log"
 )
 
-const copyBufferSize = 128 * 1024
+// DefaultCopyBufferSize is the default size of buffers used to copy proxied traffic.
+const DefaultCopyBufferSize = 128 * 1024
 
-var bufferPool = NewBufferPool(copyBufferSize)
+const (
+	idleTimeout           = 5 * time.Minute
+	halfClosedIdleTimeout = 30 * time.Second
+)
 
-func proxyHTTP1(ctx context.Context, left, right net.Conn) {
+// proxyHTTP1 copies data both ways until both directions are finished and returns
+// the side which closed first: "client", "remote" or "idle" when the tunnel timed out.
+func proxyHTTP1(ctx context.Context, buffers *BufferPool, left, right net.Conn) string {
 	wg := sync.WaitGroup{}
 
-	idleTimeout := 5 * time.Minute
-	timeout := time.AfterFunc(idleTimeout, func() {
+	var closedFirst string
+	var closedOnce sync.Once
+	timers := newTunnelTimers(idleTimeout, func() {
+		closedOnce.Do(func() {
+			closedFirst = "idle"
+		})
 		left.Close()
 		right.Close()
 	})
-	extend := func() {
-		timeout.Reset(idleTimeout)
-	}
+	defer timers.stop()
 
-	cpy := func(dst, src net.Conn) {
+	cpy := func(dst, src net.Conn, side string, timeout, other *idleTimer) {
 		defer wg.Done()
 
-		copyBuffer(dst, src, extend)
-		dst.Close()
+		_, err := copyBuffer(buffers, dst, src, timeout.extend)
+		timeout.finish()
+		closedOnce.Do(func() {
+			closedFirst = side
+		})
+		other.setTimeout(halfClosedIdleTimeout)
+		if err != nil {
+			dst.Close()
+			src.Close()
+			return
+		}
+		halfClose(dst)
 	}
 	wg.Add(2)
-	go cpy(left, right)
-	go cpy(right, left)
+	go cpy(left, right, "remote", timers.toClient, timers.toRemote)
+	go cpy(right, left, "client", timers.toRemote, timers.toClient)
 	groupDone := make(chan struct{}, 1)
 	go func() {
 		wg.Wait()
@@ -62,33 +82,35 @@ func proxyHTTP1(ctx context.Context, left, right net.Conn) {
 	case <-ctx.Done():
 		left.Close()
 		right.Close()
+		<-groupDone
 	case <-groupDone:
-		return
 	}
-	<-groupDone
-	return
+	log.Debug().Msgf("Tunnel %s <-> %s finished, %s side closed first", left.RemoteAddr(), right.RemoteAddr(), closedFirst)
+	return closedFirst
 }
 
-func proxyHTTP2(ctx context.Context, leftreader io.ReadCloser, leftwriter io.Writer, right net.Conn) {
+func proxyHTTP2(ctx context.Context, buffers *BufferPool, leftreader io.ReadCloser, leftwriter io.Writer, right net.Conn) {
 	wg := sync.WaitGroup{}
 
-	idleTimeout := 5 * time.Minute
-	timeout := time.AfterFunc(idleTimeout, func() {
+	timeout := newIdleTimer(idleTimeout, func() {
 		leftreader.Close()
 		right.Close()
 	})
-	extend := func() {
-		timeout.Reset(idleTimeout)
-	}
+	defer timeout.stop()
 
 	ltr := func(dst net.Conn, src io.Reader) {
 		defer wg.Done()
-		copyBuffer(dst, src, extend)
-		dst.Close()
+		_, err := copyBuffer(buffers, dst, src, timeout.extend)
+		timeout.setTimeout(halfClosedIdleTimeout)
+		if err != nil {
+			dst.Close()
+			return
+		}
+		halfClose(dst)
 	}
 	rtl := func(dst io.Writer, src io.Reader) {
 		defer wg.Done()
-		copyBody(dst, src)
+		copyBody(buffers, dst, src)
 	}
 	wg.Add(2)
 	go ltr(right, leftreader)
@@ -109,6 +131,112 @@ func proxyHTTP2(ctx context.Context, leftreader io.ReadCloser, leftwriter io.Wri
 	return
 }
 
+type closeWriter interface {
+	CloseWrite() error
+}
+
+// halfClose propagates EOF to the connection, keeping the opposite direction open when possible.
+func halfClose(conn net.Conn) {
+	if cw, ok := conn.(closeWriter); ok {
+		if err := cw.CloseWrite(); err == nil {
+			return
+		}
+	}
+	conn.Close()
+}
+
+// idleTimer calls onIdle once no data has passed through a tunnel for the current timeout.
+type idleTimer struct {
+	mu      sync.Mutex
+	timer   *time.Timer
+	timeout time.Duration
+	idle    bool
+	done    bool
+}
+
+func newIdleTimer(timeout time.Duration, onIdle func()) *idleTimer {
+	t := &idleTimer{timeout: timeout}
+	t.timer = time.AfterFunc(timeout, func() {
+		t.mu.Lock()
+		t.idle = true
+		t.mu.Unlock()
+
+		onIdle()
+	})
+	return t
+}
+
+func (t *idleTimer) extend() {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+
+	t.idle = false
+	t.timer.Reset(t.timeout)
+}
+
+func (t *idleTimer) setTimeout(timeout time.Duration) {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+
+	t.timeout = timeout
+	if !t.done {
+		t.timer.Reset(timeout)
+	}
+}
+
+// finish marks the direction as done, it stays idle from now on.
+func (t *idleTimer) finish() {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+
+	t.idle = true
+	t.done = true
+	t.timer.Stop()
+}
+
+func (t *idleTimer) isIdle() bool {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+
+	return t.idle
+}
+
+func (t *idleTimer) stop() {
+	t.timer.Stop()
+}
+
+// tunnelTimers keeps an idle timer per tunnel direction and calls onIdle once both
+// of them are idle, so a long download is not cut off because nothing is uploaded.
+type tunnelTimers struct {
+	mu       sync.Mutex
+	toClient *idleTimer
+	toRemote *idleTimer
+}
+
+func newTunnelTimers(timeout time.Duration, onIdle func()) *tunnelTimers {
+	tt := &tunnelTimers{}
+	checkIdle := func() {
+		tt.mu.Lock()
+		defer tt.mu.Unlock()
+
+		if tt.toClient.isIdle() && tt.toRemote.isIdle() {
+			onIdle()
+		}
+	}
+
+	tt.mu.Lock()
+	defer tt.mu.Unlock()
+
+	tt.toClient = newIdleTimer(timeout, checkIdle)
+	tt.toRemote = newIdleTimer(timeout, checkIdle)
+	return tt
+}
+
+func (tt *tunnelTimers) stop() {
+	tt.toClient.stop()
+	tt.toRemote.stop()
+}
+
 // Hop-by-hop headers. These are removed when sent to the backend.
 // http://www.w3.org/Protocols/rfc2616/rfc2616-sec13.html
 var hopHeaders = []string{
@@ -164,9 +292,9 @@ func flush(flusher interface{}) bool {
 	return true
 }
 
-func copyBody(wr io.Writer, body io.Reader) {
-	buf := bufferPool.Get()
-	defer bufferPool.Put(buf)
+func copyBody(buffers *BufferPool, wr io.Writer, body io.Reader) {
+	buf := buffers.Get()
+	defer buffers.Put(buf)
 
 	for {
 		bread, readErr := body.Read(buf)
@@ -181,9 +309,9 @@ func copyBody(wr io.Writer, body io.Reader) {
 	}
 }
 
-func copyBuffer(dst io.Writer, src io.Reader, extend func()) (written int64, err error) {
-	buf := bufferPool.Get()
-	defer bufferPool.Put(buf)
+func copyBuffer(buffers *BufferPool, dst io.Writer, src io.Reader, extend func()) (written int64, err error) {
+	buf := buffers.Get()
+	defer buffers.Put(buf)
 
 	for {
 		extend()
@@ -0,0 +1,163 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package proxyclient
+
+import (
+	"context"
+	"io"
+	"net"
+	"strconv"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func Test_ProxyHTTP1_KeepsResponseDirectionOpenAfterClientHalfClose(t *testing.T) {
+	client, proxyLeft := tcpPair(t)
+	proxyRight, server := tcpPair(t)
+
+	closedBy := make(chan string, 1)
+	go func() {
+		closedBy <- proxyHTTP1(context.Background(), NewBufferPool(DefaultCopyBufferSize), proxyLeft, proxyRight)
+	}()
+
+	_, err := client.Write([]byte("ping"))
+	require.NoError(t, err)
+	require.NoError(t, client.CloseWrite())
+
+	request, err := io.ReadAll(server)
+	require.NoError(t, err)
+	assert.Equal(t, "ping", string(request))
+
+	_, err = server.Write([]byte("pong"))
+	require.NoError(t, err)
+	require.NoError(t, server.Close())
+
+	response, err := io.ReadAll(client)
+	require.NoError(t, err)
+	assert.Equal(t, "pong", string(response))
+
+	assert.Equal(t, "client", <-closedBy)
+}
+
+func Test_TunnelTimers_KeepTunnelWhileOneDirectionIsActive(t *testing.T) {
+	closed := make(chan struct{})
+	timers := newTunnelTimers(100*time.Millisecond, func() { close(closed) })
+	defer timers.stop()
+
+	for i := 0; i < 10; i++ {
+		timers.toClient.extend()
+		time.Sleep(10 * time.Millisecond)
+	}
+	select {
+	case <-closed:
+		t.Fatal("tunnel closed while data was flowing to the client")
+	default:
+	}
+
+	select {
+	case <-closed:
+	case <-time.After(time.Second):
+		t.Fatal("tunnel was not closed after both directions went idle")
+	}
+}
+
+func Test_TunnelTimers_ClosesAfterRemainingDirectionTimesOut(t *testing.T) {
+	closed := make(chan struct{})
+	timers := newTunnelTimers(time.Minute, func() { close(closed) })
+	defer timers.stop()
+
+	timers.toRemote.finish()
+	timers.toClient.setTimeout(10 * time.Millisecond)
+
+	select {
+	case <-closed:
+	case <-time.After(time.Second):
+		t.Fatal("half-closed tunnel was not closed after its timeout")
+	}
+}
+
+func tcpPair(t testing.TB) (*net.TCPConn, *net.TCPConn) {
+	listener, err := net.Listen("tcp", "127.0.0.1:0")
+	require.NoError(t, err)
+	defer listener.Close()
+
+	accepted := make(chan net.Conn, 1)
+	go func() {
+		conn, err := listener.Accept()
+		if err == nil {
+			accepted <- conn
+		}
+		close(accepted)
+	}()
+
+	dialed, err := net.Dial("tcp", listener.Addr().String())
+	require.NoError(t, err)
+	conn, ok := <-accepted
+	require.True(t, ok)
+
+	t.Cleanup(func() {
+		dialed.Close()
+		conn.Close()
+	})
+	return dialed.(*net.TCPConn), conn.(*net.TCPConn)
+}
+
+func Benchmark_ProxyHTTP1_Throughput(b *testing.B) {
+	for _, size := range []int{8 * 1024, 32 * 1024, DefaultCopyBufferSize, 512 * 1024} {
+		b.Run(strconv.Itoa(size/1024)+"KiB", func(b *testing.B) {
+			benchmarkProxyThroughput(b, size)
+		})
+	}
+}
+
+func benchmarkProxyThroughput(b *testing.B, bufferSize int) {
+	client, proxyLeft := tcpPair(b)
+	proxyRight, server := tcpPair(b)
+
+	ctx, cancel := context.WithCancel(context.Background())
+	proxyDone := make(chan struct{})
+	go func() {
+		proxyHTTP1(ctx, NewBufferPool(bufferSize), proxyLeft, proxyRight)
+		close(proxyDone)
+	}()
+
+	chunk := make([]byte, 1024*1024)
+	b.SetBytes(int64(len(chunk)))
+	b.ResetTimer()
+
+	writerDone := make(chan struct{})
+	go func() {
+		defer close(writerDone)
+		for i := 0; i < b.N; i++ {
+			client.Write(chunk)
+		}
+		client.CloseWrite()
+	}()
+
+	// Stop the proxy and the writer after every run, so they don't pile up across b.N iterations.
+	b.Cleanup(func() {
+		cancel()
+		<-proxyDone
+		<-writerDone
+	})
+
+	io.Copy(io.Discard, server)
+}
@@ -48,7 +48,7 @@ func NewWireguardClient() (*client, error) {
 
 func (c *client) ConfigureDevice(config wgcfg.DeviceConfig) (err error) {
 	rollback := actionstack.NewActionStack()
-	if c.tun, err = CreateTUN(config.IfaceName, config.Subnet); err != nil {
+	if c.tun, err = CreateTUN(config.IfaceName, config.Subnet, config.TunnelMTU()); err != nil {
 		return errors.Wrap(err, "failed to create TUN device")
 	}
 
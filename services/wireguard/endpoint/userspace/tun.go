@@ -24,13 +24,12 @@ import (
 
 	"github.com/mysteriumnetwork/node/utils/netutil"
 	"github.com/pkg/errors"
-	"golang.zx2c4.com/wireguard/device"
 	"golang.zx2c4.com/wireguard/tun"
 )
 
 // CreateTUN creates native TUN device for wireguard.
-func CreateTUN(name string, subnet net.IPNet) (tunDevice tun.Device, err error) {
-	if tunDevice, err = tun.CreateTUN(name, device.DefaultMTU); err != nil {
+func CreateTUN(name string, subnet net.IPNet, mtu int) (tunDevice tun.Device, err error) {
+	if tunDevice, err = tun.CreateTUN(name, mtu); err != nil {
 		return nil, errors.Wrap(err, "failed to create TUN device")
 	}
 	if err = netutil.AssignIP(name, subnet); err != nil {
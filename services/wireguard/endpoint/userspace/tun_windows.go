@@ -27,17 +27,17 @@ import (
 	"github.com/mysteriumnetwork/node/utils/netutil"
 	"github.com/pkg/errors"
 	"github.com/songgao/water"
-	"golang.zx2c4.com/wireguard/device"
 	"golang.zx2c4.com/wireguard/tun"
 )
 
 type nativeTun struct {
 	tun    *water.Interface
 	events chan tun.Event
+	mtu    int
 }
 
 // CreateTUN creates native TUN device for wireguard.
-func CreateTUN(name string, subnet net.IPNet) (tun.Device, error) {
+func CreateTUN(name string, subnet net.IPNet, mtu int) (tun.Device, error) {
 	tunDevice, err := water.New(water.Config{
 		DeviceType: water.TUN,
 		PlatformSpecificParams: water.PlatformSpecificParams{
@@ -62,6 +62,7 @@ func CreateTUN(name string, subnet net.IPNet) (tun.Device, error) {
 	return &nativeTun{
 		tun:    tunDevice,
 		events: make(chan tun.Event, 10),
+		mtu:    mtu,
 	}, nil
 }
 
@@ -117,7 +118,7 @@ func (tun *nativeTun) Flush() error {
 }
 
 func (tun *nativeTun) MTU() (int, error) {
-	return device.DefaultMTU, nil
+	return tun.mtu, nil
 }
 
 func renameInterface(name, newname string) error {
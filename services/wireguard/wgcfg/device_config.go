@@ -27,6 +27,7 @@ import (
 	"time"
 
 	"github.com/rs/zerolog/log"
+	"golang.zx2c4.com/wireguard/device"
 )
 
 // Stats represents wireguard peer statistics information.
@@ -54,6 +55,14 @@ type DeviceConfig struct {
 	ProxyPort int `json:"proxy_port,omitempty"`
 }
 
+// TunnelMTU returns configured interface MTU or WireGuard default if it is not set.
+func (dc DeviceConfig) TunnelMTU() int {
+	if dc.MTU > 0 {
+		return dc.MTU
+	}
+	return device.DefaultMTU
+}
+
 // MarshalJSON implements json.Marshaler interface to provide human readable configuration.
 func (dc DeviceConfig) MarshalJSON() ([]byte, error) {
 	type peer struct {
@@ -163,6 +163,11 @@ func TestDeviceConfig_UnmarshalJSON(t *testing.T) {
 	}
 }
 
+func TestDeviceConfig_TunnelMTU(t *testing.T) {
+	assert.Equal(t, 1420, DeviceConfig{}.TunnelMTU())
+	assert.Equal(t, 1280, DeviceConfig{MTU: 1280}.TunnelMTU())
+}
+
 func endpoint() *net.UDPAddr {
 	res, _ := net.ResolveUDPAddr("udp", "182.122.22.19:3233")
 	return res
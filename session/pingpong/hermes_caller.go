@@ -363,6 +363,29 @@ func (ac *HermesCaller) GetProviderData(chainID int64, id string) (HermesUserInf
 	return ac.getProviderData(chainID, id)
 }
 
+// GetProviderDataCached gets provider data from hermes, reusing the one fetched within cacheTime.
+func (ac *HermesCaller) GetProviderDataCached(chainID int64, id string, cacheTime time.Duration) (HermesUserInfo, error) {
+	// Provider data is cached apart from consumer data of the same identity.
+	cacheKey := "provider:" + id
+	cachedResponse, cachedError, ok := ac.getResponseFromCache(chainID, cacheKey, cacheTime)
+	if ok {
+		return cachedResponse, cachedError
+	}
+
+	data, err := ac.getProviderData(chainID, id)
+	if err != nil {
+		if errors.Is(err, ErrHermesNotFound) {
+			// also save not found status
+			ac.setCacheData(chainID, cacheKey, nil, err)
+		}
+		return HermesUserInfo{}, err
+	}
+
+	ac.setCacheData(chainID, cacheKey, &data, nil)
+
+	return data, nil
+}
+
 // ProviderPromiseAmountUnsafe returns the provider promise amount.
 // If can also return `nil` as the result if no promise exists.
 func (ac *HermesCaller) ProviderPromiseAmountUnsafe(chainID int64, id string) (*big.Int, error) {
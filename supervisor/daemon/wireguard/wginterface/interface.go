@@ -42,7 +42,7 @@ type WgInterface struct {
 
 // New creates new WgInterface instance.
 func New(cfg wgcfg.DeviceConfig, uid string) (*WgInterface, error) {
-	tunnel, interfaceName, err := createTunnel(cfg.IfaceName, cfg.DNS)
+	tunnel, interfaceName, err := createTunnel(cfg.IfaceName, cfg.DNS, cfg.TunnelMTU())
 	if err != nil {
 		return nil, fmt.Errorf("failed to create TUN device %s: %w", cfg.IfaceName, err)
 	}
@@ -18,15 +18,22 @@
 package client
 
 import (
+	"bufio"
+	"context"
+	"encoding/json"
 	"fmt"
 	"io"
 	"math/big"
 	"net/http"
 	"net/url"
+	"os"
 	"strconv"
+	"strings"
+	"time"
 
 	"github.com/ethereum/go-ethereum/common"
 	"github.com/pkg/errors"
+	"github.com/rs/zerolog/log"
 
 	"github.com/mysteriumnetwork/go-rest/apierror"
 	"github.com/mysteriumnetwork/node/identity"
@@ -34,14 +41,67 @@ import (
 	"github.com/mysteriumnetwork/payments/exchange"
 )
 
+const (
+	defaultUserAgent = "goclient-v0.1"
+	defaultURL       = "http://127.0.0.1:4050"
+
+	// EnvTequilapiURL is the environment variable holding Tequilapi base URL
+	EnvTequilapiURL = "TEQUILAPI_URL"
+	// EnvTequilapiSocket is the environment variable holding path of Tequilapi unix socket
+	EnvTequilapiSocket = "TEQUILAPI_SOCKET"
+)
+
 // NewClient returns a new instance of Client
 func NewClient(ip string, port int) *Client {
-	return &Client{
-		http: newHTTPClient(
-			fmt.Sprintf("http://%s:%d", ip, port),
-			"goclient-v0.1",
-		),
+	opts := DefaultOptions()
+	opts.BaseURL = fmt.Sprintf("http://%s:%d", ip, port)
+	return NewClientWithOptions(opts)
+}
+
+// NewClientWithOptions returns a new instance of Client for Tequilapi served on unix socket
+// or base URL given in options, local Tequilapi on default port is used when neither is given
+func NewClientWithOptions(opts Options) *Client {
+	if opts.Socket != "" {
+		return newClient(newUnixSocketHTTPClient(opts.Socket, userAgent(opts), opts), opts)
+	}
+	baseURL := opts.BaseURL
+	if baseURL == "" {
+		baseURL = defaultURL
 	}
+	return newClient(newHTTPClient(strings.TrimSuffix(baseURL, "/"), userAgent(opts), opts), opts)
+}
+
+// NewNodeClient returns a new instance of Client for the node serving Tequilapi on given unix socket
+// or, if no socket is given, on given address and port
+func NewNodeClient(address string, port int, socketPath string) *Client {
+	opts := DefaultOptions()
+	opts.BaseURL = fmt.Sprintf("http://%s:%d", address, port)
+	opts.Socket = socketPath
+	return NewClientWithOptions(opts)
+}
+
+// EndpointFromEnv returns given options with Tequilapi endpoint resolved from environment.
+// Unix socket given by TEQUILAPI_SOCKET takes precedence over TEQUILAPI_URL,
+// options are returned unchanged when neither is set.
+func EndpointFromEnv(opts Options) Options {
+	if socketPath := os.Getenv(EnvTequilapiSocket); socketPath != "" {
+		opts.Socket = socketPath
+	} else if baseURL := os.Getenv(EnvTequilapiURL); baseURL != "" {
+		opts.BaseURL = baseURL
+	}
+	return opts
+}
+
+func newClient(c *httpClient, opts Options) *Client {
+	c.SetToken(opts.Token)
+	return &Client{http: c}
+}
+
+func userAgent(opts Options) string {
+	if opts.UserAgent == "" {
+		return defaultUserAgent
+	}
+	return opts.UserAgent
 }
 
 // Client is able perform remote requests to Tequilapi server
@@ -49,6 +109,11 @@ type Client struct {
 	http httpClientInterface
 }
 
+// SetToken sets auth token sent with every following request, e.g. one issued earlier by AuthAuthenticate
+func (client *Client) SetToken(token string) {
+	client.http.SetToken(token)
+}
+
 // AuthAuthenticate authenticates user and issues auth token
 func (client *Client) AuthAuthenticate(request contract.AuthRequest) (res contract.AuthResponse, err error) {
 	response, err := client.http.Post("/auth/authenticate", request)
@@ -162,6 +227,20 @@ func (client *Client) CurrentIdentity(identity, passphrase string) (id contract.
 	return id, err
 }
 
+// IdentityBalance returns the Hermes balance of identity and, if asked, its on-chain balance, in wei and formatted as MYST.
+func (client *Client) IdentityBalance(identityAddress string, onchain bool) (b contract.IdentityBalanceDTO, err error) {
+	response, err := client.http.Get(fmt.Sprintf("identities/%s/balance", identityAddress), url.Values{
+		"onchain": []string{strconv.FormatBool(onchain)},
+	})
+	if err != nil {
+		return b, err
+	}
+	defer response.Body.Close()
+
+	err = parseResponseJSON(response, &b)
+	return b, err
+}
+
 // BalanceRefresh forces a balance refresh if possible and returns the latest balance.
 func (client *Client) BalanceRefresh(identityAddress string) (b contract.BalanceDTO, err error) {
 	path := fmt.Sprintf("identities/%s/balance/refresh", identityAddress)
@@ -276,7 +355,7 @@ func (client *Client) ConnectionCreate(consumerID, providerID, hermesID, service
 
 // SmartConnectionCreate initiates a new connection to a host identified by filter
 func (client *Client) SmartConnectionCreate(consumerID, hermesID, serviceType string, filter contract.ConnectionCreateFilter, options contract.ConnectOptions) (status contract.ConnectionInfoDTO, err error) {
-	response, err := client.http.Put("connection", contract.ConnectionCreateRequest{
+	response, err := client.http.PutIdempotent("connection", contract.ConnectionCreateRequest{
 		ConsumerID:     consumerID,
 		Filter:         filter,
 		HermesID:       hermesID,
@@ -294,14 +373,21 @@ func (client *Client) SmartConnectionCreate(consumerID, hermesID, serviceType st
 
 // ConnectionDestroy terminates current connection
 func (client *Client) ConnectionDestroy(port int) (err error) {
+	_, err = client.ConnectionDisconnect(port)
+	return err
+}
+
+// ConnectionDisconnect terminates connection and returns its final statistics
+func (client *Client) ConnectionDisconnect(port int) (statistics contract.ConnectionStatisticsDTO, err error) {
 	url := fmt.Sprintf("connection?%s", url.Values{"id": []string{strconv.Itoa(port)}}.Encode())
 	response, err := client.http.Delete(url, nil)
 	if err != nil {
-		return
+		return statistics, err
 	}
 	defer response.Body.Close()
 
-	return nil
+	err = parseResponseJSON(response, &statistics)
+	return statistics, err
 }
 
 // ConnectionStatistics returns statistics about current connection
@@ -344,6 +430,28 @@ func (client *Client) ConnectionStatus(port int) (status contract.ConnectionInfo
 	return status, err
 }
 
+// WaitForConnectionStatus polls connection status, backing off between attempts,
+// until connection reaches target status or the timeout passes
+func (client *Client) WaitForConnectionStatus(port int, target string, timeout time.Duration) (status contract.ConnectionInfoDTO, err error) {
+	deadline := time.Now().Add(timeout)
+	delay := statusPollMinDelay
+	for {
+		status, err = client.ConnectionStatus(port)
+		if err == nil && status.Status == target {
+			return status, nil
+		}
+		if time.Now().Add(delay).After(deadline) {
+			if err != nil {
+				return status, err
+			}
+			return status, fmt.Errorf("connection did not reach status %s in %s, last status: %s", target, timeout, status.Status)
+		}
+
+		time.Sleep(delay)
+		delay = min(2*delay, statusPollMaxDelay)
+	}
+}
+
 // ConnectionIP returns public ip
 func (client *Client) ConnectionIP() (ip contract.IPDTO, err error) {
 	response, err := client.http.Get("connection/ip", url.Values{})
@@ -452,6 +560,47 @@ func (client *Client) ProposalsNATCompatible() ([]contract.ProposalDTO, error) {
 	return client.proposals(queryParams)
 }
 
+// ProposalFilter describes server side filtering of proposals. Zero valued fields are not applied.
+type ProposalFilter struct {
+	ProviderID       string
+	ServiceType      string
+	LocationCountry  string
+	IPType           string
+	NATCompatibility string
+	QualityMin       float32
+	PriceHourMax     *big.Int
+	PriceGiBMax      *big.Int
+}
+
+func (f ProposalFilter) query() url.Values {
+	query := url.Values{}
+	set := func(key, value string) {
+		if value != "" {
+			query.Set(key, value)
+		}
+	}
+	set("provider_id", f.ProviderID)
+	set("service_type", f.ServiceType)
+	set("location_country", f.LocationCountry)
+	set("ip_type", f.IPType)
+	set("nat_compatibility", f.NATCompatibility)
+	if f.QualityMin > 0 {
+		query.Set("quality_min", strconv.FormatFloat(float64(f.QualityMin), 'f', -1, 32))
+	}
+	if f.PriceHourMax != nil {
+		query.Set("price_hour_max", f.PriceHourMax.String())
+	}
+	if f.PriceGiBMax != nil {
+		query.Set("price_gib_max", f.PriceGiBMax.String())
+	}
+	return query
+}
+
+// ProposalsFiltered returns proposals matching given filter
+func (client *Client) ProposalsFiltered(filter ProposalFilter) ([]contract.ProposalDTO, error) {
+	return client.proposals(filter.query())
+}
+
 func (client *Client) proposals(query url.Values) ([]contract.ProposalDTO, error) {
 	response, err := client.http.Get("proposals", query)
 	if err != nil {
@@ -538,6 +687,66 @@ func (client *Client) Sessions() (sessions contract.SessionListResponse, err err
 	return sessions, err
 }
 
+// SessionsPage returns a single page of sessions from history matching the query
+func (client *Client) SessionsPage(query contract.SessionListQuery) (sessions contract.SessionListResponse, err error) {
+	response, err := client.http.Get("sessions", sessionListValues(query))
+	if err != nil {
+		return sessions, err
+	}
+	defer response.Body.Close()
+
+	err = parseResponseJSON(response, &sessions)
+	return sessions, err
+}
+
+// SessionsAll returns all sessions from history matching the query, fetching them page by page
+func (client *Client) SessionsAll(query contract.SessionQuery) ([]contract.SessionDTO, error) {
+	var sessions []contract.SessionDTO
+	pages := client.SessionPages(query)
+	for {
+		items, ok, err := pages.Next()
+		if err != nil || !ok {
+			return sessions, err
+		}
+		sessions = append(sessions, items...)
+	}
+}
+
+// SessionPages returns iterator over pages of sessions from history matching the query
+func (client *Client) SessionPages(query contract.SessionQuery) *Pages[contract.SessionDTO] {
+	pageQuery := contract.NewSessionListQuery()
+	pageQuery.SessionQuery = query
+	return NewPages(func(page int) ([]contract.SessionDTO, contract.PageableDTO, error) {
+		pageQuery.Page = page
+		sessions, err := client.SessionsPage(pageQuery)
+		return sessions.Items, sessions.PageableDTO, err
+	})
+}
+
+func sessionListValues(q contract.SessionListQuery) url.Values {
+	values := url.Values{}
+	values.Set("page", strconv.Itoa(q.Page))
+	values.Set("page_size", strconv.Itoa(q.PageSize))
+	if q.DateFrom != nil {
+		values.Set("date_from", q.DateFrom.String())
+	}
+	if q.DateTo != nil {
+		values.Set("date_to", q.DateTo.String())
+	}
+	set := func(key string, value *string) {
+		if value != nil {
+			values.Set(key, *value)
+		}
+	}
+	set("direction", q.Direction)
+	set("consumer_id", q.ConsumerID)
+	set("hermes_id", q.HermesID)
+	set("provider_id", q.ProviderID)
+	set("service_type", q.ServiceType)
+	set("status", q.Status)
+	return values
+}
+
 // SessionsByServiceType returns sessions from history filtered by type
 func (client *Client) SessionsByServiceType(serviceType string) (contract.SessionListResponse, error) {
 	sessions, err := client.Sessions()
@@ -552,6 +761,18 @@ func (client *Client) SessionsByStatus(status string) (contract.SessionListRespo
 	return sessions, err
 }
 
+// SessionKill terminates an active provider session
+func (client *Client) SessionKill(id string) error {
+	path := fmt.Sprintf("sessions/%s", id)
+	response, err := client.http.Delete(path, nil)
+	if err != nil {
+		return err
+	}
+	defer response.Body.Close()
+
+	return nil
+}
+
 // Services returns all running services
 func (client *Client) Services() (services contract.ServiceListResponse, err error) {
 	response, err := client.http.Get("services", url.Values{})
@@ -600,6 +821,88 @@ func (client *Client) ServiceStop(id string) error {
 	return nil
 }
 
+// State returns a snapshot of the daemon state, same as the payload of state change events.
+func (client *Client) State() (state json.RawMessage, err error) {
+	response, err := client.http.Get("state", nil)
+	if err != nil {
+		return nil, err
+	}
+	defer response.Body.Close()
+
+	err = parseResponseJSON(response, &state)
+	return state, err
+}
+
+const (
+	eventStreamReconnectDelay = time.Second
+	maxEventSize              = 1 << 20
+
+	statusPollMinDelay = 100 * time.Millisecond
+	statusPollMaxDelay = 2 * time.Second
+)
+
+// Event is a daemon event received from the event stream
+type Event struct {
+	Type    string          `json:"type"`
+	Payload json.RawMessage `json:"payload"`
+}
+
+// SubscribeEvents streams daemon events until the context is cancelled,
+// reconnecting whenever the stream breaks. Returned channel is closed when the context is done.
+func (client *Client) SubscribeEvents(ctx context.Context) <-chan Event {
+	events := make(chan Event)
+	go func() {
+		defer close(events)
+		for {
+			err := client.streamEvents(ctx, events)
+			if ctx.Err() != nil {
+				return
+			}
+			log.Warn().Err(err).Msg("Event stream interrupted, reconnecting")
+
+			select {
+			case <-ctx.Done():
+				return
+			case <-time.After(eventStreamReconnectDelay):
+			}
+		}
+	}()
+	return events
+}
+
+func (client *Client) streamEvents(ctx context.Context, events chan<- Event) error {
+	response, err := client.http.Stream(ctx, "events/state")
+	if err != nil {
+		return err
+	}
+	defer response.Body.Close()
+
+	scanner := bufio.NewScanner(response.Body)
+	scanner.Buffer(make([]byte, 0, 64*1024), maxEventSize)
+	for scanner.Scan() {
+		data, ok := strings.CutPrefix(scanner.Text(), "data: ")
+		if !ok {
+			continue
+		}
+
+		var event Event
+		if err := json.Unmarshal([]byte(data), &event); err != nil {
+			log.Warn().Err(err).Msg("Skipping malformed event")
+			continue
+		}
+
+		select {
+		case events <- event:
+		case <-ctx.Done():
+			return ctx.Err()
+		}
+	}
+	if err := scanner.Err(); err != nil {
+		return err
+	}
+	return errors.New("event stream closed")
+}
+
 // NATStatus returns status of NAT traversal
 func (client *Client) NATStatus() (status contract.NodeStatusResponse, err error) {
 	response, err := client.http.Get("node/monitoring-status", nil)
@@ -18,11 +18,19 @@
 package client
 
 import (
+	"context"
+	"fmt"
 	"io"
+	"math/big"
+	"net"
 	"net/http"
 	"net/http/httptest"
 	"strings"
+	"sync/atomic"
 	"testing"
+	"time"
+
+	"github.com/go-openapi/strfmt"
 
 	"github.com/mysteriumnetwork/node/core/monitoring"
 
@@ -90,6 +98,291 @@ func TestConnectionErrorIsReturnedByClientInsteadOfDoubleParsing(t *testing.T) {
 	assert.True(t, responseBody.Closed)
 }
 
+func TestGetIsRetriedOnServerError(t *testing.T) {
+	var calls int
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		calls++
+		if calls == 1 {
+			w.WriteHeader(http.StatusServiceUnavailable)
+			return
+		}
+		w.Write([]byte(`{"status": "success"}`))
+	}))
+	defer server.Close()
+
+	opts := DefaultOptions()
+	opts.Retries = 1
+	opts.RetryDelay = time.Millisecond
+	client := Client{http: newHTTPClient(server.URL, "", opts)}
+
+	status, err := client.NATStatus()
+
+	assert.NoError(t, err)
+	assert.Equal(t, monitoring.Success, status.Status)
+	assert.Equal(t, 2, calls)
+}
+
+func TestConnectionDisconnectReturnsFinalStatistics(t *testing.T) {
+	httpClient := mockHTTPClient(
+		t,
+		http.MethodDelete,
+		"/connection",
+		http.StatusAccepted,
+		`{"bytes_sent": 10, "bytes_received": 20, "duration": 30}`,
+	)
+	client := Client{http: httpClient}
+
+	statistics, err := client.ConnectionDisconnect(4050)
+
+	assert.NoError(t, err)
+	assert.Equal(t, uint64(10), statistics.BytesSent)
+	assert.Equal(t, uint64(20), statistics.BytesReceived)
+	assert.Equal(t, 30, statistics.Duration)
+}
+
+func TestProposalFilterQuery(t *testing.T) {
+	query := ProposalFilter{
+		ServiceType:     "wireguard",
+		LocationCountry: "LT",
+		QualityMin:      1.5,
+		PriceHourMax:    big.NewInt(100),
+	}.query()
+
+	assert.Equal(t, "location_country=LT&price_hour_max=100&quality_min=1.5&service_type=wireguard", query.Encode())
+}
+
+func TestSessionsAllFetchesEveryPage(t *testing.T) {
+	var queries []string
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		queries = append(queries, r.URL.RawQuery)
+		page := r.URL.Query().Get("page")
+		fmt.Fprintf(w, `{"items": [{"id": "session-%s"}], "page": %s, "page_size": 1, "total_items": 2, "total_pages": 2}`, page, page)
+	}))
+	defer server.Close()
+	client := Client{http: newHTTPClient(server.URL, "", DefaultOptions())}
+
+	date := strfmt.Date(time.Date(2026, 7, 1, 0, 0, 0, 0, time.UTC))
+	sessions, err := client.SessionsAll(contract.SessionQuery{DateFrom: &date})
+
+	assert.NoError(t, err)
+	assert.Len(t, sessions, 2)
+	assert.Equal(t, "session-1", sessions[0].ID)
+	assert.Equal(t, "session-2", sessions[1].ID)
+	assert.Equal(t, []string{
+		"date_from=2026-07-01&page=1&page_size=50",
+		"date_from=2026-07-01&page=2&page_size=50",
+	}, queries)
+}
+
+func TestSubscribeEventsReadsEventStream(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, "/events/state", r.URL.Path)
+		w.Header().Set("Content-Type", "text/event-stream")
+		fmt.Fprint(w, "data: {\"type\": \"state-change\", \"payload\": {\"nat\": {}}}\n\n")
+		fmt.Fprint(w, "data: {\"type\": \"nat\", \"payload\": {}}\n\n")
+	}))
+	defer server.Close()
+	client := Client{http: newHTTPClient(server.URL, "", DefaultOptions())}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	events := client.SubscribeEvents(ctx)
+
+	event := <-events
+	assert.Equal(t, "state-change", event.Type)
+	assert.JSONEq(t, `{"nat": {}}`, string(event.Payload))
+	event = <-events
+	assert.Equal(t, "nat", event.Type)
+
+	cancel()
+	for range events {
+	}
+}
+
+func TestSetTokenIsSentAsBearer(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, "Bearer token-1", r.Header.Get("Authorization"))
+		w.Write([]byte(`{"status": "success"}`))
+	}))
+	defer server.Close()
+	client := Client{http: newHTTPClient(server.URL, "", DefaultOptions())}
+	client.SetToken("token-1")
+
+	_, err := client.NATStatus()
+
+	assert.NoError(t, err)
+}
+
+func TestNewClientWithOptionsAppliesOptions(t *testing.T) {
+	server := httptest.NewTLSServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, "/node/monitoring-status", r.URL.Path)
+		assert.Equal(t, "test-agent", r.Header.Get("User-Agent"))
+		assert.Equal(t, "Bearer token-1", r.Header.Get("Authorization"))
+		w.Write([]byte(`{"status": "success"}`))
+	}))
+	defer server.Close()
+
+	opts := DefaultOptions()
+	opts.UserAgent = "test-agent"
+	opts.Token = "token-1"
+	opts.TLSConfig = server.Client().Transport.(*http.Transport).TLSClientConfig
+	opts.BaseURL = server.URL + "/"
+	client := NewClientWithOptions(opts)
+
+	status, err := client.NATStatus()
+
+	assert.NoError(t, err)
+	assert.Equal(t, monitoring.Success, status.Status)
+}
+
+func TestIdentityBalance(t *testing.T) {
+	httpClient := mockHTTPClient(
+		t,
+		http.MethodGet,
+		"/identities/0x1/balance",
+		http.StatusOK,
+		`{
+			"hermes": {"balance": 1500000000000000000, "balance_tokens": {"wei": "1500000000000000000", "ether": "1.5", "human": "1.5"}},
+			"onchain": {"balance": 2000000000000000000, "balance_tokens": {"wei": "2000000000000000000", "ether": "2", "human": "2"}}
+		}`,
+	)
+	client := Client{http: httpClient}
+
+	balance, err := client.IdentityBalance("0x1", true)
+
+	assert.NoError(t, err)
+	assert.Equal(t, "1500000000000000000", balance.Hermes.Balance.String())
+	assert.Equal(t, "1.5", balance.Hermes.BalanceTokens.Ether)
+	assert.NotNil(t, balance.Onchain)
+	assert.Equal(t, "2", balance.Onchain.BalanceTokens.Ether)
+}
+
+func TestEndpointFromEnv(t *testing.T) {
+	t.Setenv(EnvTequilapiURL, "https://node.example:4449/")
+	client := NewClientWithOptions(EndpointFromEnv(DefaultOptions()))
+	assert.Equal(t, "https://node.example:4449", client.http.(*httpClient).baseURL)
+
+	t.Setenv(EnvTequilapiSocket, "/run/myst/tequilapi.sock")
+	client = NewClientWithOptions(EndpointFromEnv(DefaultOptions()))
+	assert.Equal(t, "http://localhost", client.http.(*httpClient).baseURL)
+
+	t.Setenv(EnvTequilapiURL, "")
+	t.Setenv(EnvTequilapiSocket, "")
+	client = NewClientWithOptions(EndpointFromEnv(DefaultOptions()))
+	assert.Equal(t, defaultURL, client.http.(*httpClient).baseURL)
+}
+
+func TestRequestsAreTaggedWithRequestID(t *testing.T) {
+	var requestIDs []string
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		requestIDs = append(requestIDs, r.Header.Get(requestIDHeader))
+		w.Write([]byte(`{"status": "success"}`))
+	}))
+	defer server.Close()
+	client := Client{http: newHTTPClient(server.URL, "", DefaultOptions())}
+
+	_, err := client.NATStatus()
+	assert.NoError(t, err)
+	_, err = client.NATStatus()
+	assert.NoError(t, err)
+
+	assert.Len(t, requestIDs, 2)
+	assert.NotEmpty(t, requestIDs[0])
+	assert.NotEqual(t, requestIDs[0], requestIDs[1])
+}
+
+func TestWaitForConnectionStatus(t *testing.T) {
+	var calls int
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		calls++
+		if calls < 3 {
+			w.Write([]byte(`{"status": "Connecting"}`))
+			return
+		}
+		w.Write([]byte(`{"status": "Connected"}`))
+	}))
+	defer server.Close()
+	client := Client{http: newHTTPClient(server.URL, "", DefaultOptions())}
+
+	status, err := client.WaitForConnectionStatus(4050, "Connected", 5*time.Second)
+	assert.NoError(t, err)
+	assert.Equal(t, "Connected", status.Status)
+	assert.Equal(t, 3, calls)
+
+	_, err = client.WaitForConnectionStatus(4050, "NotConnected", 50*time.Millisecond)
+	assert.EqualError(t, err, "connection did not reach status NotConnected in 50ms, last status: Connected")
+}
+
+func TestSmartConnectionCreateSendsIdempotencyKey(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		assert.Equal(t, http.MethodPut, r.Method)
+		assert.NotEmpty(t, r.Header.Get(idempotencyKeyHeader))
+		w.WriteHeader(http.StatusCreated)
+		w.Write([]byte(`{"status": "Connecting"}`))
+	}))
+	defer server.Close()
+	client := Client{http: newHTTPClient(server.URL, "", DefaultOptions())}
+
+	status, err := client.SmartConnectionCreate("consumer", "hermes", "wireguard", contract.ConnectionCreateFilter{}, contract.ConnectOptions{})
+
+	assert.NoError(t, err)
+	assert.Equal(t, "Connecting", status.Status)
+}
+
+func TestConnectionsAreReusedWithKeepAlive(t *testing.T) {
+	var connections atomic.Int32
+	server := httptest.NewUnstartedServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.Write([]byte(`{"status": "success"}`))
+	}))
+	server.Config.ConnState = func(_ net.Conn, state http.ConnState) {
+		if state == http.StateNew {
+			connections.Add(1)
+		}
+	}
+	server.Start()
+	defer server.Close()
+
+	client := Client{http: newHTTPClient(server.URL, "", DefaultOptions())}
+	for i := 0; i < 3; i++ {
+		_, err := client.NATStatus()
+		assert.NoError(t, err)
+	}
+	assert.Equal(t, int32(1), connections.Load())
+
+	opts := DefaultOptions()
+	opts.MaxIdleConnsPerHost = 0
+	client = Client{http: newHTTPClient(server.URL, "", opts)}
+	for i := 0; i < 2; i++ {
+		_, err := client.NATStatus()
+		assert.NoError(t, err)
+	}
+	assert.Equal(t, int32(3), connections.Load())
+}
+
+func TestHTTP2IsAttemptedUnlessDisabled(t *testing.T) {
+	var protoMajor atomic.Int32
+	server := httptest.NewUnstartedServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		protoMajor.Store(int32(r.ProtoMajor))
+		w.Write([]byte(`{"status": "success"}`))
+	}))
+	server.EnableHTTP2 = true
+	server.StartTLS()
+	defer server.Close()
+
+	opts := DefaultOptions()
+	opts.TLSConfig = server.Client().Transport.(*http.Transport).TLSClientConfig.Clone()
+	client := Client{http: newHTTPClient(server.URL, "", opts)}
+	_, err := client.NATStatus()
+	assert.NoError(t, err)
+	assert.Equal(t, int32(2), protoMajor.Load())
+
+	opts.TLSConfig = server.Client().Transport.(*http.Transport).TLSClientConfig.Clone()
+	opts.ForceAttemptHTTP2 = false
+	client = Client{http: newHTTPClient(server.URL, "", opts)}
+	_, err = client.NATStatus()
+	assert.NoError(t, err)
+	assert.Equal(t, int32(1), protoMajor.Load())
+}
+
 func mockHTTPClient(t *testing.T, method, url string, statusCode int, response string) httpClientInterface {
 	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
 		assert.Equal(t, method, r.Method)
@@ -97,7 +390,7 @@ func mockHTTPClient(t *testing.T, method, url string, statusCode int, response s
 		w.Write([]byte(response))
 		w.WriteHeader(statusCode)
 	}))
-	return newHTTPClient(server.URL, "")
+	return newHTTPClient(server.URL, "", DefaultOptions())
 }
 
 type requestDoer func(req *http.Request) (*http.Response, error)
@@ -19,13 +19,17 @@ package client
 
 import (
 	"bytes"
+	"context"
+	"crypto/tls"
 	"encoding/json"
 	"fmt"
 	"io"
+	"net"
 	"net/http"
 	"net/url"
 	"time"
 
+	"github.com/gofrs/uuid"
 	"github.com/mysteriumnetwork/go-rest/apierror"
 	"github.com/rs/zerolog/log"
 
@@ -37,26 +41,120 @@ type httpClientInterface interface {
 	Get(path string, values url.Values) (*http.Response, error)
 	Post(path string, payload interface{}) (*http.Response, error)
 	Put(path string, payload interface{}) (*http.Response, error)
+	PutIdempotent(path string, payload interface{}) (*http.Response, error)
 	Delete(path string, payload interface{}) (*http.Response, error)
+	Stream(ctx context.Context, path string) (*http.Response, error)
 }
 
+const (
+	requestIDHeader      = "X-Request-ID"
+	idempotencyKeyHeader = "Idempotency-Key"
+)
+
 type httpRequestInterface interface {
 	Do(req *http.Request) (*http.Response, error)
 }
 
-func newHTTPClient(baseURL string, ua string) *httpClient {
+// Options configures endpoint, timeouts and retries of requests made to Tequilapi.
+type Options struct {
+	// BaseURL is where Tequilapi is served, e.g. https://node.example:4449.
+	BaseURL string
+	// Socket is path of unix socket Tequilapi is served on, takes precedence over BaseURL.
+	Socket string
+	// ConnectTimeout bounds establishing of a connection to Tequilapi.
+	ConnectTimeout time.Duration
+	// RequestTimeout bounds whole request, including reading of the response body.
+	RequestTimeout time.Duration
+	// Retries is how many times idempotent GET request is repeated after a transport or 5xx error.
+	// PUT tagged with an idempotency key is repeated the same number of times, after a transport error only.
+	Retries int
+	// RetryDelay is a pause between request retries.
+	RetryDelay time.Duration
+	// UserAgent is sent with every request, defaults to the client version.
+	UserAgent string
+	// Token is auth token sent with every request.
+	Token string
+	// TLSConfig is used when Tequilapi is served over https.
+	TLSConfig *tls.Config
+	// MaxIdleConnsPerHost is how many connections are kept open for reuse, zero disables keep-alive.
+	MaxIdleConnsPerHost int
+	// IdleConnTimeout is how long an unused connection is kept open.
+	IdleConnTimeout time.Duration
+	// ForceAttemptHTTP2 makes client try HTTP/2 when Tequilapi is served over https with custom TLS config.
+	ForceAttemptHTTP2 bool
+}
+
+// DefaultOptions returns options used by NewClient.
+func DefaultOptions() Options {
+	return Options{
+		ConnectTimeout: 60 * time.Second,
+		RequestTimeout: 100 * time.Second,
+		Retries:        0,
+		RetryDelay:     time.Second,
+
+		MaxIdleConnsPerHost: 2,
+		IdleConnTimeout:     90 * time.Second,
+		ForceAttemptHTTP2:   true,
+	}
+}
+
+func applyPoolOptions(transport *http.Transport, opts Options) {
+	transport.DisableKeepAlives = opts.MaxIdleConnsPerHost <= 0
+	transport.MaxIdleConnsPerHost = opts.MaxIdleConnsPerHost
+	transport.IdleConnTimeout = opts.IdleConnTimeout
+	transport.ForceAttemptHTTP2 = opts.ForceAttemptHTTP2
+}
+
+func newHTTPClient(baseURL string, ua string, opts Options) *httpClient {
+	dialer := &net.Dialer{
+		Timeout:   opts.ConnectTimeout,
+		KeepAlive: 30 * time.Second,
+		LocalAddr: &net.TCPAddr{IP: net.ParseIP("0.0.0.0")},
+	}
+	transport := requests.NewTransport(dialer.DialContext)
+	transport.TLSClientConfig = opts.TLSConfig
+	applyPoolOptions(transport, opts)
 	return &httpClient{
-		http:    requests.NewHTTPClient("0.0.0.0", 100*time.Second),
-		baseURL: baseURL,
-		ua:      ua,
+		http:       &http.Client{Timeout: opts.RequestTimeout, Transport: transport},
+		stream:     &http.Client{Transport: transport},
+		baseURL:    baseURL,
+		ua:         ua,
+		retries:    opts.Retries,
+		retryDelay: opts.RetryDelay,
+	}
+}
+
+func newUnixSocketHTTPClient(socketPath string, ua string, opts Options) *httpClient {
+	dialer := net.Dialer{Timeout: opts.ConnectTimeout}
+	transport := &http.Transport{
+		DialContext: func(ctx context.Context, _, _ string) (net.Conn, error) {
+			return dialer.DialContext(ctx, "unix", socketPath)
+		},
+	}
+	applyPoolOptions(transport, opts)
+	return &httpClient{
+		http: &http.Client{
+			Timeout:   opts.RequestTimeout,
+			Transport: transport,
+		},
+		stream: &http.Client{
+			Transport: transport,
+		},
+		baseURL:    "http://localhost",
+		ua:         ua,
+		retries:    opts.Retries,
+		retryDelay: opts.RetryDelay,
 	}
 }
 
 type httpClient struct {
-	http      httpRequestInterface
-	authToken string
-	baseURL   string
-	ua        string
+	http       httpRequestInterface
+	stream     httpRequestInterface
+	authToken  string
+	baseURL    string
+	ua         string
+	retries    int
+	retryDelay time.Duration
 }
 
 func (client *httpClient) SetToken(token string) {
@@ -73,7 +171,21 @@ func (client *httpClient) Get(path string, values url.Values) (*http.Response, e
 	} else {
 		fullPath = fmt.Sprintf("%v?%v", basePath, params)
 	}
-	return client.executeRequest("GET", fullPath, nil)
+
+	for attempt := 0; ; attempt++ {
+		response, err := client.executeRequest("GET", fullPath, nil, nil)
+		if err == nil || attempt >= client.retries || !isRetriable(response) {
+			return response, err
+		}
+		if response != nil {
+			response.Body.Close()
+		}
+		time.Sleep(client.retryDelay)
+	}
+}
+
+func isRetriable(response *http.Response) bool {
+	return response == nil || response.StatusCode >= http.StatusInternalServerError
 }
 
 func (client *httpClient) Post(path string, payload interface{}) (*http.Response, error) {
@@ -84,10 +196,58 @@ func (client *httpClient) Put(path string, payload interface{}) (*http.Response,
 	return client.doPayloadRequest("PUT", path, payload)
 }
 
+// PutIdempotent performs PUT tagged with an idempotency key, which lets it be retried
+// on transport errors as the server ignores repeated requests with the same key.
+func (client *httpClient) PutIdempotent(path string, payload interface{}) (*http.Response, error) {
+	payloadJSON, err := json.Marshal(payload)
+	if err != nil {
+		return nil, err
+	}
+	key, err := uuid.NewV4()
+	if err != nil {
+		return nil, err
+	}
+	header := http.Header{}
+	header.Set(idempotencyKeyHeader, key.String())
+
+	for attempt := 0; ; attempt++ {
+		response, err := client.executeRequest("PUT", client.baseURL+"/"+path, payloadJSON, header)
+		if err == nil || response != nil || attempt >= client.retries {
+			return response, err
+		}
+		time.Sleep(client.retryDelay)
+	}
+}
+
 func (client *httpClient) Delete(path string, payload interface{}) (*http.Response, error) {
 	return client.doPayloadRequest("DELETE", path, payload)
 }
 
+// Stream performs GET request without request timeout, for reading long-lived responses.
+func (client *httpClient) Stream(ctx context.Context, path string) (*http.Response, error) {
+	request, err := http.NewRequestWithContext(ctx, http.MethodGet, client.baseURL+"/"+path, nil)
+	if err != nil {
+		return nil, err
+	}
+	request.Header.Set("User-Agent", client.ua)
+	request.Header.Set("Accept", "text/event-stream")
+	if client.authToken != "" {
+		request.Header.Set("Authorization", "Bearer "+client.authToken)
+	}
+
+	response, err := client.do(client.stream, request)
+	if err != nil {
+		return nil, err
+	}
+
+	if err := parseResponseError(response); err != nil {
+		response.Body.Close()
+		return nil, err
+	}
+
+	return response, nil
+}
+
 func (client httpClient) doPayloadRequest(method, path string, payload interface{}) (*http.Response, error) {
 	payloadJSON, err := json.Marshal(payload)
 	if err != nil {
@@ -95,15 +255,18 @@ func (client httpClient) doPayloadRequest(method, path string, payload interface
 		return nil, err
 	}
 
-	return client.executeRequest(method, client.baseURL+"/"+path, payloadJSON)
+	return client.executeRequest(method, client.baseURL+"/"+path, payloadJSON, nil)
 }
 
-func (client *httpClient) executeRequest(method, fullPath string, payloadJSON []byte) (*http.Response, error) {
+func (client *httpClient) executeRequest(method, fullPath string, payloadJSON []byte, header http.Header) (*http.Response, error) {
 	request, err := http.NewRequest(method, fullPath, bytes.NewBuffer(payloadJSON))
 	if err != nil {
 		log.Error().Err(err).Msg("")
 		return nil, err
 	}
+	for key, values := range header {
+		request.Header[key] = values
+	}
 	request.Header.Set("User-Agent", client.ua)
 	request.Header.Set("Content-Type", "application/json")
 	request.Header.Set("Accept", "application/json")
@@ -111,7 +274,7 @@ func (client *httpClient) executeRequest(method, fullPath string, payloadJSON []
 		request.Header.Set("Authorization", "Bearer "+client.authToken)
 	}
 
-	response, err := client.http.Do(request)
+	response, err := client.do(client.http, request)
 	if err != nil {
 		log.Error().Err(err).Msg("")
 		return response, err
@@ -126,6 +289,30 @@ func (client *httpClient) executeRequest(method, fullPath string, payloadJSON []
 	return response, nil
 }
 
+// do sends request tagged with a request ID and logs its outcome, so client and Tequilapi logs can be matched.
+func (client *httpClient) do(doer httpRequestInterface, request *http.Request) (*http.Response, error) {
+	var requestID string
+	if uid, err := uuid.NewV4(); err == nil {
+		requestID = uid.String()
+		request.Header.Set(requestIDHeader, requestID)
+	}
+
+	start := time.Now()
+	response, err := doer.Do(request)
+
+	event := log.Debug().
+		Str("request_id", requestID).
+		Str("method", request.Method).
+		Str("path", request.URL.Path).
+		Dur("latency", time.Since(start))
+	if response != nil {
+		event = event.Int("status", response.StatusCode)
+	}
+	event.Err(err).Msg("Tequilapi request")
+
+	return response, err
+}
+
 func parseResponseError(response *http.Response) error {
 	if response.StatusCode < 200 || response.StatusCode >= 300 {
 		return apierror.Parse(response)
@@ -0,0 +1,50 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package client
+
+import "github.com/mysteriumnetwork/node/tequilapi/contract"
+
+// Pages iterates over a paginated list endpoint, fetching pages on demand.
+type Pages[T any] struct {
+	fetch func(page int) ([]T, contract.PageableDTO, error)
+	next  int
+	done  bool
+}
+
+// NewPages returns iterator calling fetch for each following page, starting with the first one.
+func NewPages[T any](fetch func(page int) ([]T, contract.PageableDTO, error)) *Pages[T] {
+	return &Pages[T]{fetch: fetch, next: 1}
+}
+
+// Next fetches items of the next page. It returns false once all pages were read.
+func (p *Pages[T]) Next() ([]T, bool, error) {
+	if p.done {
+		return nil, false, nil
+	}
+
+	items, page, err := p.fetch(p.next)
+	if err != nil {
+		return nil, false, err
+	}
+	if page.Page >= page.TotalPages {
+		p.done = true
+	}
+	p.next = page.Page + 1
+
+	return items, true, nil
+}
@@ -0,0 +1,59 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package client
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/mysteriumnetwork/node/tequilapi/contract"
+)
+
+func TestPagesFetchesUntilLastPage(t *testing.T) {
+	var fetched []int
+	pages := NewPages(func(page int) ([]int, contract.PageableDTO, error) {
+		fetched = append(fetched, page)
+		return []int{page * 10}, contract.PageableDTO{Page: page, TotalPages: 3}, nil
+	})
+
+	var items []int
+	for {
+		pageItems, ok, err := pages.Next()
+		assert.NoError(t, err)
+		if !ok {
+			break
+		}
+		items = append(items, pageItems...)
+	}
+
+	assert.Equal(t, []int{10, 20, 30}, items)
+	assert.Equal(t, []int{1, 2, 3}, fetched)
+}
+
+func TestPagesReturnsFetchError(t *testing.T) {
+	pages := NewPages(func(page int) ([]int, contract.PageableDTO, error) {
+		return nil, contract.PageableDTO{}, errors.New("boom")
+	})
+
+	_, ok, err := pages.Next()
+
+	assert.False(t, ok)
+	assert.EqualError(t, err, "boom")
+}
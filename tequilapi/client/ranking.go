@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package client
+
+import (
+	"sort"
+	"strconv"
+
+	"github.com/mysteriumnetwork/node/tequilapi/contract"
+)
+
+// ProposalScorer scores proposal, proposals with higher scores are ranked first.
+type ProposalScorer func(p contract.ProposalDTO) float64
+
+// ScoreWeights weighs proposal metrics in WeightedScorer.
+type ScoreWeights struct {
+	// Quality rewards each point of quality oracle score.
+	Quality float64
+	// Latency penalises each millisecond of latency.
+	Latency float64
+	// PriceGiB penalises each MYST charged per GiB.
+	PriceGiB float64
+	// PriceHour penalises each MYST charged per hour.
+	PriceHour float64
+}
+
+// DefaultScoreWeights prefer quality, using latency and price to rank providers of similar quality.
+var DefaultScoreWeights = ScoreWeights{
+	Quality:   1,
+	Latency:   0.001,
+	PriceGiB:  1,
+	PriceHour: 1,
+}
+
+// WeightedScorer returns scorer rewarding quality and penalising latency and price with given weights.
+func WeightedScorer(w ScoreWeights) ProposalScorer {
+	return func(p contract.ProposalDTO) float64 {
+		return w.Quality*p.Quality.Quality -
+			w.Latency*p.Quality.Latency -
+			w.PriceGiB*tokensAmount(p.Price.PerGiBTokens) -
+			w.PriceHour*tokensAmount(p.Price.PerHourTokens)
+	}
+}
+
+// RankProposals returns proposals sorted from the best to the worst score. Proposals with equal scores keep their order.
+func RankProposals(proposals []contract.ProposalDTO, score ProposalScorer) []contract.ProposalDTO {
+	type scored struct {
+		proposal contract.ProposalDTO
+		score    float64
+	}
+
+	ranking := make([]scored, len(proposals))
+	for i, p := range proposals {
+		ranking[i] = scored{proposal: p, score: score(p)}
+	}
+	sort.SliceStable(ranking, func(i, j int) bool {
+		return ranking[i].score > ranking[j].score
+	})
+
+	ranked := make([]contract.ProposalDTO, len(ranking))
+	for i, r := range ranking {
+		ranked[i] = r.proposal
+	}
+	return ranked
+}
+
+func tokensAmount(t contract.Tokens) float64 {
+	amount, err := strconv.ParseFloat(t.Ether, 64)
+	if err != nil {
+		return 0
+	}
+	return amount
+}
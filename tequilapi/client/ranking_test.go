@@ -0,0 +1,51 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package client
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/mysteriumnetwork/node/tequilapi/contract"
+)
+
+func TestRankProposals(t *testing.T) {
+	proposal := func(id string, quality, latency float64, priceGiB string) contract.ProposalDTO {
+		return contract.ProposalDTO{
+			ProviderID: id,
+			Quality:    contract.Quality{Quality: quality, Latency: latency},
+			Price:      contract.Price{PerGiBTokens: contract.Tokens{Ether: priceGiB}},
+		}
+	}
+	proposals := []contract.ProposalDTO{
+		proposal("slow", 2, 900, "0.1"),
+		proposal("expensive", 2, 100, "1.0"),
+		proposal("best", 2, 100, "0.1"),
+		proposal("poor", 0.5, 10, "0.01"),
+	}
+
+	ranked := RankProposals(proposals, WeightedScorer(DefaultScoreWeights))
+
+	var ids []string
+	for _, p := range ranked {
+		ids = append(ids, p.ProviderID)
+	}
+	assert.Equal(t, []string{"best", "slow", "expensive", "poor"}, ids)
+	assert.Equal(t, "slow", proposals[0].ProviderID, "input must not be reordered")
+}
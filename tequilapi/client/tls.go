@@ -0,0 +1,64 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package client
+
+import (
+	"bytes"
+	"crypto/sha256"
+	"crypto/tls"
+	"crypto/x509"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"strings"
+)
+
+// TLSConfigWithRootCA returns TLS config trusting server certificates issued by given PEM encoded
+// root certificate, e.g. self-signed certificate of the node.
+func TLSConfigWithRootCA(caPEM []byte) (*tls.Config, error) {
+	pool := x509.NewCertPool()
+	if !pool.AppendCertsFromPEM(caPEM) {
+		return nil, errors.New("no certificates found in root CA PEM")
+	}
+	return &tls.Config{RootCAs: pool, MinVersion: tls.VersionTLS12}, nil
+}
+
+// TLSConfigWithPinnedCert returns TLS config accepting only the server certificate
+// with given hex encoded SHA-256 fingerprint, regardless of who issued it.
+func TLSConfigWithPinnedCert(fingerprint string) (*tls.Config, error) {
+	pinned, err := hex.DecodeString(strings.ReplaceAll(fingerprint, ":", ""))
+	if err != nil || len(pinned) != sha256.Size {
+		return nil, fmt.Errorf("invalid SHA-256 certificate fingerprint: %q", fingerprint)
+	}
+
+	return &tls.Config{
+		MinVersion: tls.VersionTLS12,
+		// Chain verification is replaced by the fingerprint check below.
+		InsecureSkipVerify: true,
+		VerifyPeerCertificate: func(rawCerts [][]byte, _ [][]*x509.Certificate) error {
+			if len(rawCerts) == 0 {
+				return errors.New("server presented no certificate")
+			}
+			actual := sha256.Sum256(rawCerts[0])
+			if !bytes.Equal(actual[:], pinned) {
+				return errors.New("server certificate does not match pinned fingerprint")
+			}
+			return nil
+		},
+	}, nil
+}
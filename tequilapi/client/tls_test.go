@@ -0,0 +1,81 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package client
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/pem"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/mysteriumnetwork/node/core/monitoring"
+)
+
+func newTLSStatusServer() *httptest.Server {
+	return httptest.NewTLSServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.Write([]byte(`{"status": "success"}`))
+	}))
+}
+
+func TestTLSConfigWithRootCA(t *testing.T) {
+	server := newTLSStatusServer()
+	defer server.Close()
+
+	caPEM := pem.EncodeToMemory(&pem.Block{Type: "CERTIFICATE", Bytes: server.Certificate().Raw})
+	tlsConfig, err := TLSConfigWithRootCA(caPEM)
+	assert.NoError(t, err)
+
+	opts := DefaultOptions()
+	opts.TLSConfig = tlsConfig
+	opts.BaseURL = server.URL
+	status, err := NewClientWithOptions(opts).NATStatus()
+
+	assert.NoError(t, err)
+	assert.Equal(t, monitoring.Success, status.Status)
+
+	_, err = TLSConfigWithRootCA([]byte("not a certificate"))
+	assert.Error(t, err)
+}
+
+func TestTLSConfigWithPinnedCert(t *testing.T) {
+	server := newTLSStatusServer()
+	defer server.Close()
+
+	fingerprint := sha256.Sum256(server.Certificate().Raw)
+	tlsConfig, err := TLSConfigWithPinnedCert(hex.EncodeToString(fingerprint[:]))
+	assert.NoError(t, err)
+
+	opts := DefaultOptions()
+	opts.TLSConfig = tlsConfig
+	opts.BaseURL = server.URL
+	_, err = NewClientWithOptions(opts).NATStatus()
+	assert.NoError(t, err)
+
+	otherFingerprint := sha256.Sum256([]byte("other"))
+	opts.TLSConfig, err = TLSConfigWithPinnedCert(hex.EncodeToString(otherFingerprint[:]))
+	assert.NoError(t, err)
+	_, err = NewClientWithOptions(opts).NATStatus()
+	assert.Error(t, err)
+
+	_, err = TLSConfigWithPinnedCert("abc")
+	assert.Error(t, err)
+}
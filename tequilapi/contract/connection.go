@@ -19,6 +19,7 @@ package contract
 
 import (
 	"math/big"
+	"time"
 
 	"github.com/ethereum/go-ethereum/common"
 
@@ -36,9 +37,10 @@ var emptyAddress = common.Address{}
 // NewConnectionInfoDTO maps to API connection status.
 func NewConnectionInfoDTO(session connectionstate.Status) ConnectionInfoDTO {
 	response := ConnectionInfoDTO{
-		Status:     string(session.State),
-		ConsumerID: session.ConsumerID.Address,
-		SessionID:  string(session.SessionID),
+		Status:           string(session.State),
+		ConsumerID:       session.ConsumerID.Address,
+		SessionID:        string(session.SessionID),
+		DisconnectReason: session.DisconnectReason,
 	}
 	if session.HermesID != emptyAddress {
 		response.HermesID = session.HermesID.Hex()
@@ -68,15 +70,18 @@ type ConnectionInfoDTO struct {
 
 	// example: 4cfb0324-daf6-4ad8-448b-e61fe0a1f918
 	SessionID string `json:"session_id,omitempty"`
+
+	// example: data cap reached
+	DisconnectReason string `json:"disconnect_reason,omitempty"`
 }
 
 // NewConnectionDTO maps to API connection.
-func NewConnectionDTO(session connectionstate.Status, statistics connectionstate.Statistics, throughput bandwidth.Throughput, invoice crypto.Invoice) ConnectionDTO {
+func NewConnectionDTO(session connectionstate.Status, statistics connectionstate.Statistics, throughput bandwidth.Throughput, invoice crypto.Invoice, latency time.Duration) ConnectionDTO {
 	dto := ConnectionDTO{
 		ConnectionInfoDTO: NewConnectionInfoDTO(session),
 	}
 	if !statistics.At.IsZero() {
-		statsDto := NewConnectionStatisticsDTO(session, statistics, throughput, invoice)
+		statsDto := NewConnectionStatisticsDTO(session, statistics, throughput, invoice, latency)
 		dto.Statistics = &statsDto
 	}
 	return dto
@@ -89,8 +94,24 @@ type ConnectionDTO struct {
 	Statistics *ConnectionStatisticsDTO `json:"statistics,omitempty"`
 }
 
+// ListedConnectionDTO holds consumer connection details along with its proxy port.
+// swagger:model ListedConnectionDTO
+type ListedConnectionDTO struct {
+	ConnectionDTO
+
+	// proxy port the connection is served on, used as connection id by other connection endpoints
+	// example: 10000
+	ProxyPort int `json:"proxy_port"`
+}
+
+// ListConnectionsResponse holds all managed consumer connections.
+// swagger:model ListConnectionsResponse
+type ListConnectionsResponse struct {
+	Connections []ListedConnectionDTO `json:"connections"`
+}
+
 // NewConnectionStatisticsDTO maps to API connection stats.
-func NewConnectionStatisticsDTO(session connectionstate.Status, statistics connectionstate.Statistics, throughput bandwidth.Throughput, invoice crypto.Invoice) ConnectionStatisticsDTO {
+func NewConnectionStatisticsDTO(session connectionstate.Status, statistics connectionstate.Statistics, throughput bandwidth.Throughput, invoice crypto.Invoice, latency time.Duration) ConnectionStatisticsDTO {
 	agreementTotal := new(big.Int)
 	if invoice.AgreementTotal != nil {
 		agreementTotal = invoice.AgreementTotal
@@ -101,6 +122,7 @@ func NewConnectionStatisticsDTO(session connectionstate.Status, statistics conne
 		BytesReceived:      statistics.BytesReceived,
 		ThroughputSent:     datasize.BitSize(throughput.Up).Bits(),
 		ThroughputReceived: datasize.BitSize(throughput.Down).Bits(),
+		Latency:            latency.Milliseconds(),
 		TokensSpent:        agreementTotal,
 		SpentTokens:        NewTokens(agreementTotal),
 	}
@@ -123,6 +145,10 @@ type ConnectionStatisticsDTO struct {
 	// example: 1024
 	ThroughputReceived uint64 `json:"throughput_received"`
 
+	// Round trip time to the provider in milliseconds, measured by p2p keep-alive pings
+	// example: 45
+	Latency int64 `json:"latency"`
+
 	// connection duration in seconds
 	// example: 60
 	Duration int `json:"duration"`
@@ -171,6 +197,11 @@ type ConnectionCreateRequest struct {
 	// connect options
 	// required: false
 	ConnectOptions ConnectOptions `json:"connect_options,omitempty"`
+
+	// tear down existing connection on the same proxy port instead of rejecting the request
+	// required: false
+	// example: false
+	Force bool `json:"force,omitempty"`
 }
 
 // ConnectionCreateFilter describes filter for the connection request to lookup
@@ -189,6 +220,9 @@ func (cr ConnectionCreateRequest) Validate() *apierror.APIError {
 	if len(cr.ConsumerID) == 0 {
 		v.Required("consumer_id")
 	}
+	if cr.ConnectOptions.ProxyPort < 0 || cr.ConnectOptions.ProxyPort > 65535 {
+		v.Invalid("proxy_port", "Should be between 0 and 65535")
+	}
 	return v.Err()
 }
 
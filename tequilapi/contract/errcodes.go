@@ -39,6 +39,8 @@ const (
 	ErrCodeIDGetBeneficiaryAddress       = "err_id_get_beneficiary_address"
 	ErrCodeHermesMigration               = "err_id_check_hermes_migration"
 	ErrCodeCheckHermesMigrationStatus    = "err_id_check_hermes_migration_status"
+	ErrCodeIDBalanceHermes               = "err_id_balance_hermes"
+	ErrCodeIDBalanceOnchain              = "err_id_balance_onchain"
 
 	// Payment
 
@@ -133,6 +135,7 @@ const (
 	ErrCodeHermesFee                       = "err_hermes_fee"
 	ErrCodeHermesSettle                    = "err_hermes_settle"
 	ErrCodeHermesSettleAsync               = "err_hermes_settle_async"
+	ErrCodeHermesUserData                  = "err_hermes_user_data"
 	ErrCodeUILocalVersions                 = "err_ui_local_versions"
 	ErrCodeUISwitchVersion                 = "err_ui_switch_version"
 	ErrCodeUIDownload                      = "err_ui_download"
@@ -0,0 +1,70 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package contract
+
+import (
+	"math/big"
+	"time"
+
+	"github.com/mysteriumnetwork/node/session/pingpong"
+)
+
+// NewHermesUserInfoDTO maps to API Hermes user info.
+func NewHermesUserInfoDTO(info pingpong.HermesUserInfo) HermesUserInfoDTO {
+	return HermesUserInfoDTO{
+		Identity:            info.Identity,
+		Beneficiary:         info.Beneficiary,
+		ChannelID:           info.ChannelID,
+		Balance:             info.Balance,
+		Settled:             info.Settled,
+		Stake:               info.Stake,
+		LatestPromiseAmount: info.LatestPromise.Amount,
+		LatestSettlement:    info.LatestSettlement,
+		IsOffchain:          info.IsOffchain,
+	}
+}
+
+// HermesUserInfoDTO represents identity data as seen by Hermes.
+// swagger:model HermesUserInfoDTO
+type HermesUserInfoDTO struct {
+	// example: 0x0000000000000000000000000000000000000001
+	Identity string `json:"identity"`
+
+	// Beneficiary - eth wallet address
+	Beneficiary string `json:"beneficiary"`
+
+	// example: 0x8fc5f7a1794dc39c6837df10613bddf1ec9810503a50306a8667f702457a739a
+	ChannelID string `json:"channel_id"`
+
+	// example: 19449034049997187
+	Balance *big.Int `json:"balance"`
+
+	// example: 0
+	Settled *big.Int `json:"settled"`
+
+	// example: 0
+	Stake *big.Int `json:"stake"`
+
+	// Amount of the latest promise issued for the channel
+	// example: 19449034049997187
+	LatestPromiseAmount *big.Int `json:"latest_promise_amount"`
+
+	LatestSettlement time.Time `json:"latest_settlement"`
+
+	IsOffchain bool `json:"is_offchain"`
+}
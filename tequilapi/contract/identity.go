@@ -33,6 +33,14 @@ type IdentityRefDTO struct {
 	// required: true
 	// example: 0x0000000000000000000000000000000000000001
 	Address string `json:"id"`
+
+	// whether this identity is the one used by default, only set in identity list
+	// example: true
+	Default bool `json:"default,omitempty"`
+
+	// registration status of the identity, only set in identity list
+	// example: Registered
+	RegistrationStatus string `json:"registration_status,omitempty"`
 }
 
 // BalanceDTO holds balance information.
@@ -42,6 +50,21 @@ type BalanceDTO struct {
 	BalanceTokens Tokens   `json:"balance_tokens"`
 }
 
+// IdentityBalanceDTO holds consumer balance of identity in Hermes and, if requested, on-chain.
+// swagger:model IdentityBalanceDTO
+type IdentityBalanceDTO struct {
+	Hermes  BalanceDTO  `json:"hermes"`
+	Onchain *BalanceDTO `json:"onchain,omitempty"`
+}
+
+// NewBalanceDTO maps balance in wei to API balance.
+func NewBalanceDTO(balance *big.Int) BalanceDTO {
+	return BalanceDTO{
+		Balance:       balance,
+		BalanceTokens: NewTokens(balance),
+	}
+}
+
 // IdentityDTO holds identity information.
 // swagger:model IdentityDTO
 type IdentityDTO struct {
@@ -18,6 +18,8 @@
 package contract
 
 import (
+	"time"
+
 	"github.com/mysteriumnetwork/node/config"
 	"github.com/mysteriumnetwork/terms/terms-go"
 )
@@ -44,6 +46,22 @@ type TermsResponse struct {
 	AgreedVersion string `json:"agreed_version"`
 	// example: 0.0.27
 	CurrentVersion string `json:"current_version"`
+	// example: 2026-01-02T15:04:05Z
+	AgreedProviderAt string `json:"agreed_provider_at,omitempty"`
+	// example: 2026-01-02T15:04:05Z
+	AgreedConsumerAt string `json:"agreed_consumer_at,omitempty"`
+	// example: 0.0.27
+	AgreedProviderVersion string `json:"agreed_provider_version,omitempty"`
+	// example: 0.0.27
+	AgreedConsumerVersion string `json:"agreed_consumer_version,omitempty"`
+	// OutdatedProvider is true when provider terms were agreed to, but in a version
+	// other than the current one and need to be accepted again.
+	// example: false
+	OutdatedProvider bool `json:"outdated_provider"`
+	// OutdatedConsumer is true when consumer terms were agreed to, but in a version
+	// other than the current one and need to be accepted again.
+	// example: false
+	OutdatedConsumer bool `json:"outdated_consumer"`
 }
 
 const (
@@ -61,28 +79,70 @@ const (
 	// version for both provider and consumer.
 	// This key can also be used to address the value directly in the config.
 	TermsVersion = "terms.version"
+
+	// TermsConsumerAgreedAt is the key which is used to store the time
+	// of the last consumer terms agreement in RFC3339 format.
+	TermsConsumerAgreedAt = "terms.consumer-agreed-at"
+
+	// TermsProviderAgreedAt is the key which is used to store the time
+	// of the last provider terms agreement in RFC3339 format.
+	TermsProviderAgreedAt = "terms.provider-agreed-at"
+
+	// TermsConsumerVersion is the key which is used to store the terms
+	// version of the last consumer terms agreement.
+	TermsConsumerVersion = "terms.consumer-version"
+
+	// TermsProviderVersion is the key which is used to store the terms
+	// version of the last provider terms agreement.
+	TermsProviderVersion = "terms.provider-version"
 )
 
 // NewTermsResp builds and returns terms agreement response.
 func NewTermsResp() *TermsResponse {
-	return &TermsResponse{
-		AgreedProvider: config.Current.GetBool(TermsProviderAgreed),
-		AgreedConsumer: config.Current.GetBool(TermsConsumerAgreed),
-		AgreedVersion:  config.Current.GetString(TermsVersion),
-		CurrentVersion: terms.TermsVersion,
+	resp := &TermsResponse{
+		AgreedProvider:        config.Current.GetBool(TermsProviderAgreed),
+		AgreedConsumer:        config.Current.GetBool(TermsConsumerAgreed),
+		AgreedVersion:         config.Current.GetString(TermsVersion),
+		CurrentVersion:        terms.TermsVersion,
+		AgreedProviderAt:      config.Current.GetString(TermsProviderAgreedAt),
+		AgreedConsumerAt:      config.Current.GetString(TermsConsumerAgreedAt),
+		AgreedProviderVersion: agreedVersion(TermsProviderVersion),
+		AgreedConsumerVersion: agreedVersion(TermsConsumerVersion),
+	}
+	resp.OutdatedProvider = resp.AgreedProvider && resp.AgreedProviderVersion != resp.CurrentVersion
+	resp.OutdatedConsumer = resp.AgreedConsumer && resp.AgreedConsumerVersion != resp.CurrentVersion
+	return resp
+}
+
+// agreedVersion returns the terms version stored under given key, falling back to the
+// shared version for agreements given before versions were stored per side.
+func agreedVersion(key string) string {
+	if version := config.Current.GetString(key); version != "" {
+		return version
 	}
+	return config.Current.GetString(TermsVersion)
 }
 
 // ToMap turns a TermsRequest in to an iterable map which
 // can be mapped directly to a user config.
 func (t *TermsRequest) ToMap() map[string]interface{} {
+	now := time.Now().UTC().Format(time.RFC3339)
+
 	give := map[string]interface{}{}
 	if t.AgreedConsumer != nil {
 		give[TermsConsumerAgreed] = *t.AgreedConsumer
+		if *t.AgreedConsumer {
+			give[TermsConsumerAgreedAt] = now
+			give[TermsConsumerVersion] = t.AgreedVersion
+		}
 	}
 
 	if t.AgreedProvider != nil {
 		give[TermsProviderAgreed] = *t.AgreedProvider
+		if *t.AgreedProvider {
+			give[TermsProviderAgreedAt] = now
+			give[TermsProviderVersion] = t.AgreedVersion
+		}
 	}
 	give[TermsVersion] = t.AgreedVersion
 
@@ -0,0 +1,66 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package contract
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/mysteriumnetwork/node/config"
+	"github.com/mysteriumnetwork/terms/terms-go"
+)
+
+func TestTermsRequest_ToMapRecordsAgreementTime(t *testing.T) {
+	agreed, declined := true, false
+	req := TermsRequest{AgreedConsumer: &agreed, AgreedProvider: &declined, AgreedVersion: "0.0.27"}
+
+	give := req.ToMap()
+
+	assert.Equal(t, true, give[TermsConsumerAgreed])
+	assert.Equal(t, false, give[TermsProviderAgreed])
+	assert.Equal(t, "0.0.27", give[TermsVersion])
+	assert.Equal(t, "0.0.27", give[TermsConsumerVersion])
+	assert.NotContains(t, give, TermsProviderAgreedAt)
+	assert.NotContains(t, give, TermsProviderVersion)
+
+	agreedAt, err := time.Parse(time.RFC3339, give[TermsConsumerAgreedAt].(string))
+	assert.NoError(t, err)
+	assert.WithinDuration(t, time.Now(), agreedAt, time.Minute)
+}
+
+func TestNewTermsResp_FlagsOutdatedPerSide(t *testing.T) {
+	config.Current.SetUser(TermsConsumerAgreed, true)
+	config.Current.SetUser(TermsConsumerVersion, "0.0.1")
+	config.Current.SetUser(TermsProviderAgreed, true)
+	config.Current.SetUser(TermsProviderVersion, terms.TermsVersion)
+	defer func() {
+		config.Current.RemoveUser(TermsConsumerAgreed)
+		config.Current.RemoveUser(TermsConsumerVersion)
+		config.Current.RemoveUser(TermsProviderAgreed)
+		config.Current.RemoveUser(TermsProviderVersion)
+	}()
+
+	resp := NewTermsResp()
+
+	assert.Equal(t, "0.0.1", resp.AgreedConsumerVersion)
+	assert.True(t, resp.OutdatedConsumer)
+	assert.Equal(t, terms.TermsVersion, resp.AgreedProviderVersion)
+	assert.False(t, resp.OutdatedProvider)
+}
@@ -29,6 +29,7 @@ import (
 	"github.com/mysteriumnetwork/go-rest/apierror"
 	"github.com/mysteriumnetwork/node/core/auth"
 	"github.com/mysteriumnetwork/node/tequilapi/contract"
+	"github.com/mysteriumnetwork/node/tequilapi/middlewares"
 	"github.com/mysteriumnetwork/node/tequilapi/sso"
 	"github.com/mysteriumnetwork/node/tequilapi/utils"
 )
@@ -303,12 +304,14 @@ func AddRoutesForAuthentication(auth authenticator, jwtAuth jwtAuthenticator, ss
 		jwtAuthenticator: jwtAuth,
 		ssoMystnodes:     ssoMystnodes,
 	}
+	// Limits password guessing.
+	passwordLimiter := middlewares.NewRateLimiter(passphraseAttemptInterval, passphraseAttemptBurst)
 	return func(e *gin.Engine) error {
 		g := e.Group("/auth")
 		{
-			g.PUT("/password", api.ChangePassword)
-			g.POST("/authenticate", api.Authenticate)
-			g.POST("/login", api.Login)
+			g.PUT("/password", passwordLimiter, api.ChangePassword)
+			g.POST("/authenticate", passwordLimiter, api.Authenticate)
+			g.POST("/login", passwordLimiter, api.Login)
 			g.GET("/login-mystnodes", api.LoginMystnodesInit)
 			g.POST("/login-mystnodes", api.LoginMystnodesWithGrant)
 			g.DELETE("/logout", api.Logout)
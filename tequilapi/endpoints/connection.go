@@ -18,10 +18,15 @@
 package endpoints
 
 import (
+	"context"
+	"crypto/sha256"
 	"encoding/json"
 	"fmt"
 	"net/http"
+	"sort"
 	"strconv"
+	"sync"
+	"time"
 
 	"github.com/ethereum/go-ethereum/common"
 	"github.com/gin-gonic/gin"
@@ -30,8 +35,10 @@ import (
 	"github.com/mysteriumnetwork/go-rest/apierror"
 	"github.com/mysteriumnetwork/node/config"
 	"github.com/mysteriumnetwork/node/core/connection"
+	"github.com/mysteriumnetwork/node/core/connection/connectionstate"
 	"github.com/mysteriumnetwork/node/core/discovery/proposal"
 	"github.com/mysteriumnetwork/node/core/quality"
+	stateEvent "github.com/mysteriumnetwork/node/core/state/event"
 	"github.com/mysteriumnetwork/node/eventbus"
 	"github.com/mysteriumnetwork/node/identity"
 	"github.com/mysteriumnetwork/node/identity/registry"
@@ -44,8 +51,83 @@ const (
 	// statusConnectCancelled indicates that connect request was cancelled by user. Since there is no such concept in REST
 	// operations, custom client error code is defined. Maybe in later times a better idea will come how to handle these situations
 	statusConnectCancelled = 499
+
+	// IdempotencyKeyHeader carries a client generated key of connection request, so retried requests don't connect twice
+	IdempotencyKeyHeader = "Idempotency-Key"
+	idempotencyKeyTTL    = 10 * time.Minute
 )
 
+// idempotencyKeys tracks keys of connection requests, so duplicates of a request being handled wait for it
+// and duplicates of a successful request replay its result for a while
+type idempotencyKeys struct {
+	mu       sync.Mutex
+	requests map[string]*idempotentRequest
+}
+
+type idempotentRequest struct {
+	done      chan struct{}
+	succeeded bool
+	handledAt time.Time
+}
+
+func newIdempotencyKeys() *idempotencyKeys {
+	return &idempotencyKeys{requests: make(map[string]*idempotentRequest)}
+}
+
+// idempotencyScope binds the key to the request it was sent with, so reusing a key for another request doesn't replay.
+func idempotencyScope(key string, cr *contract.ConnectionCreateRequest) string {
+	body, _ := json.Marshal(cr)
+	return fmt.Sprintf("%s:%d:%x", key, cr.ConnectOptions.ProxyPort, sha256.Sum256(body))
+}
+
+// acquire reserves the key for the caller, which must call release once the request is handled.
+// If the key is reserved by a request being handled, it waits for that request: replay is returned
+// when it succeeded, otherwise the key is reserved again.
+func (k *idempotencyKeys) acquire(ctx context.Context, key string) (release func(succeeded bool), replay bool, err error) {
+	for {
+		k.mu.Lock()
+		k.evictExpired()
+		req, ok := k.requests[key]
+		if !ok {
+			req = &idempotentRequest{done: make(chan struct{})}
+			k.requests[key] = req
+			k.mu.Unlock()
+			return func(succeeded bool) { k.release(key, req, succeeded) }, false, nil
+		}
+		k.mu.Unlock()
+
+		select {
+		case <-req.done:
+			if req.succeeded {
+				return nil, true, nil
+			}
+		case <-ctx.Done():
+			return nil, false, ctx.Err()
+		}
+	}
+}
+
+func (k *idempotencyKeys) release(key string, req *idempotentRequest, succeeded bool) {
+	k.mu.Lock()
+	req.succeeded = succeeded
+	if succeeded {
+		req.handledAt = time.Now()
+	} else if k.requests[key] == req {
+		delete(k.requests, key)
+	}
+	k.mu.Unlock()
+
+	close(req.done)
+}
+
+func (k *idempotencyKeys) evictExpired() {
+	for key, req := range k.requests {
+		if !req.handledAt.IsZero() && time.Since(req.handledAt) > idempotencyKeyTTL {
+			delete(k.requests, key)
+		}
+	}
+}
+
 // ProposalGetter defines interface to fetch currently active service proposal by id
 type ProposalGetter interface {
 	GetProposal(id market.ProposalID) (*market.ServiceProposal, error)
@@ -64,6 +146,7 @@ type ConnectionEndpoint struct {
 	proposalRepository proposalRepository
 	identityRegistry   identityRegistry
 	addressProvider    addressProvider
+	idempotencyKeys    *idempotencyKeys
 }
 
 // NewConnectionEndpoint creates and returns connection endpoint
@@ -75,6 +158,7 @@ func NewConnectionEndpoint(manager connection.MultiManager, stateProvider stateP
 		proposalRepository: proposalRepository,
 		identityRegistry:   identityRegistry,
 		addressProvider:    addressProvider,
+		idempotencyKeys:    newIdempotencyKeys(),
 	}
 }
 
@@ -135,7 +219,7 @@ func (ce *ConnectionEndpoint) Status(c *gin.Context) {
 //	    schema:
 //	      "$ref": "#/definitions/APIError"
 //	  422:
-//	    description: Unable to process the request at this point
+//	    description: Unable to process the request at this point (e.g. connection on the proxy port already exists and force is not set)
 //	    schema:
 //	      "$ref": "#/definitions/APIError"
 //	  500:
@@ -162,6 +246,22 @@ func (ce *ConnectionEndpoint) Create(c *gin.Context) {
 		return
 	}
 
+	connected := false
+	if idempotencyKey := c.GetHeader(IdempotencyKeyHeader); idempotencyKey != "" {
+		release, replay, err := ce.idempotencyKeys.acquire(c.Request.Context(), idempotencyScope(idempotencyKey, cr))
+		if err != nil {
+			c.Error(apierror.Unprocessable("Connection request cancelled", contract.ErrCodeConnectionCancelled))
+			return
+		}
+		if replay {
+			log.Info().Msgf("Connection request %q was already handled, returning current status", idempotencyKey)
+			c.Status(http.StatusCreated)
+			utils.WriteAsJSON(contract.NewConnectionInfoDTO(ce.manager.Status(cr.ConnectOptions.ProxyPort)), c.Writer)
+			return
+		}
+		defer func() { release(connected) }()
+	}
+
 	consumerID := identity.FromAddress(cr.ConsumerID)
 	status, err := ce.identityRegistry.GetRegistrationStatus(config.GetInt64(config.FlagChainID), consumerID)
 	if err != nil {
@@ -204,6 +304,13 @@ func (ce *ConnectionEndpoint) Create(c *gin.Context) {
 	}
 	proposalLookup := connection.FilteredProposals(f, cr.Filter.SortBy, ce.proposalRepository)
 
+	if cr.Force {
+		if err := ce.manager.Disconnect(cr.ConnectOptions.ProxyPort); err != nil && err != connection.ErrNoConnection {
+			c.Error(apierror.Internal("Could not disconnect existing connection: "+err.Error(), contract.ErrCodeDisconnect))
+			return
+		}
+	}
+
 	err = ce.manager.Connect(consumerID, common.HexToAddress(cr.HermesID), proposalLookup, getConnectOptions(cr))
 	if err != nil {
 		switch err {
@@ -221,6 +328,8 @@ func (ce *ConnectionEndpoint) Create(c *gin.Context) {
 		return
 	}
 
+	connected = true
+
 	ce.publisher.Publish(quality.AppTopicConnectionEvents, cr.Event(quality.StageConnectionOK, ""))
 	c.Status(http.StatusCreated)
 
@@ -234,10 +343,12 @@ func (ce *ConnectionEndpoint) Create(c *gin.Context) {
 //
 //	---
 //	summary: Stops connection
-//	description: Stops current connection
+//	description: Stops current connection and returns its final statistics
 //	responses:
 //	  202:
 //	    description: Connection stopped
+//	    schema:
+//	      "$ref": "#/definitions/ConnectionStatisticsDTO"
 //	  400:
 //	    description: Failed to parse or request validation failed
 //	    schema:
@@ -262,6 +373,15 @@ func (ce *ConnectionEndpoint) Kill(c *gin.Context) {
 		}
 	}
 
+	// Snapshot the statistics before disconnecting, as the connection state is dropped afterwards.
+	// Without a session there is nothing to snapshot, so empty statistics are returned.
+	var conn stateEvent.Connection
+	if status := ce.manager.Status(n); status.SessionID != "" {
+		conn = ce.stateProvider.GetConnection(string(status.SessionID))
+		conn.Session = status
+		conn.Statistics = ce.manager.Stats(n)
+	}
+
 	err := ce.manager.Disconnect(n)
 	if err != nil {
 		switch err {
@@ -272,7 +392,10 @@ func (ce *ConnectionEndpoint) Kill(c *gin.Context) {
 		}
 		return
 	}
+
 	c.Status(http.StatusAccepted)
+	response := contract.NewConnectionStatisticsDTO(conn.Session, conn.Statistics, conn.Throughput, conn.Invoice, conn.Latency)
+	utils.WriteAsJSON(response, c.Writer)
 }
 
 // GetStatistics returns statistics about current connection
@@ -290,7 +413,36 @@ func (ce *ConnectionEndpoint) GetStatistics(c *gin.Context) {
 	id := c.Query("id")
 	conn := ce.stateProvider.GetConnection(id)
 
-	response := contract.NewConnectionStatisticsDTO(conn.Session, conn.Statistics, conn.Throughput, conn.Invoice)
+	response := contract.NewConnectionStatisticsDTO(conn.Session, conn.Statistics, conn.Throughput, conn.Invoice, conn.Latency)
+	utils.WriteAsJSON(response, c.Writer)
+}
+
+// List returns all managed connections
+// swagger:operation GET /connections Connection connectionList
+//
+//	---
+//	summary: Returns all connections
+//	description: Returns status and statistics of every active connection
+//	responses:
+//	  200:
+//	    description: List of connections
+//	    schema:
+//	      "$ref": "#/definitions/ListConnectionsResponse"
+func (ce *ConnectionEndpoint) List(c *gin.Context) {
+	response := contract.ListConnectionsResponse{Connections: []contract.ListedConnectionDTO{}}
+	for _, conn := range ce.stateProvider.GetState().Connections {
+		if conn.Session.State == "" || conn.Session.State == connectionstate.NotConnected {
+			continue
+		}
+		response.Connections = append(response.Connections, contract.ListedConnectionDTO{
+			ConnectionDTO: contract.NewConnectionDTO(conn.Session, conn.Statistics, conn.Throughput, conn.Invoice, conn.Latency),
+			ProxyPort:     conn.Session.ProxyPort,
+		})
+	}
+	sort.Slice(response.Connections, func(i, j int) bool {
+		return response.Connections[i].ProxyPort < response.Connections[j].ProxyPort
+	})
+
 	utils.WriteAsJSON(response, c.Writer)
 }
 
@@ -355,6 +507,7 @@ func AddRoutesForConnection(
 			connGroup.DELETE("/connection", connectionEndpoint.Kill)
 			connGroup.GET("/connection/statistics", connectionEndpoint.GetStatistics)
 			connGroup.GET("/connection/traffic", connectionEndpoint.GetTraffic)
+			connGroup.GET("/connections", connectionEndpoint.List)
 		}
 		return nil
 	}
@@ -49,11 +49,14 @@ type mockConnectionManager struct {
 	onDisconnectReturn   error
 	onCheckChannelReturn error
 	onStatusReturn       connectionstate.Status
+	onStatsReturn        connectionstate.Statistics
 	disconnectCount      int
+	connectCount         int
 	requestedConsumerID  identity.Identity
 	requestedProvider    identity.Identity
 	requestedHermesID    common.Address
 	requestedServiceType string
+	onConnect            func()
 }
 
 func (cm *mockConnectionManager) Connect(consumerID identity.Identity, hermesID common.Address, proposalLookup connection.ProposalLookup, options connection.ConnectParams) error {
@@ -62,6 +65,11 @@ func (cm *mockConnectionManager) Connect(consumerID identity.Identity, hermesID
 		return errors.New("no proposal")
 	}
 
+	if cm.onConnect != nil {
+		cm.onConnect()
+	}
+
+	cm.connectCount++
 	cm.requestedConsumerID = consumerID
 	cm.requestedHermesID = hermesID
 	cm.requestedProvider = identity.FromAddress(proposal.ProviderID)
@@ -74,7 +82,7 @@ func (cm *mockConnectionManager) Status(int) connectionstate.Status {
 }
 
 func (cm *mockConnectionManager) Stats(int) connectionstate.Statistics {
-	return connectionstate.Statistics{}
+	return cm.onStatsReturn
 }
 
 func (cm *mockConnectionManager) Disconnect(int) error {
@@ -137,7 +145,20 @@ func TestAddRoutesForConnectionAddsRoutes(t *testing.T) {
 		},
 		{
 			http.MethodDelete, "/connection", "",
-			http.StatusAccepted, "",
+			http.StatusAccepted, `{
+				"bytes_sent": 0,
+				"bytes_received": 0,
+				"throughput_received": 0,
+				"throughput_sent": 0,
+				"latency": 0,
+				"duration": 0,
+				"tokens_spent": 0,
+				"spent_tokens": {
+					"ether": "0",
+					"human": "0",
+					"wei": "0"
+				}
+			}`,
 		},
 		{
 			http.MethodGet, "/connection/statistics", "",
@@ -146,6 +167,7 @@ func TestAddRoutesForConnectionAddsRoutes(t *testing.T) {
 				"bytes_received": 2,
 				"throughput_received": 0,
 				"throughput_sent": 0,
+				"latency": 0,
 				"duration": 0,
 				"tokens_spent": 0,
 				"spent_tokens": {
@@ -370,13 +392,22 @@ func TestPutWithServiceTypeOverridesDefault(t *testing.T) {
 }
 
 func TestDeleteCallsDisconnect(t *testing.T) {
-	fakeManager := mockConnectionManager{}
+	fakeManager := mockConnectionManager{
+		onStatusReturn: connectionstate.Status{State: connectionstate.Connected, SessionID: "1"},
+		onStatsReturn:  connectionstate.Statistics{BytesSent: 3, BytesReceived: 4},
+	}
+	fakeState := &mockStateProvider{stateToReturn: event.State{Connections: make(map[string]event.Connection)}}
+	fakeState.stateToReturn.Connections["1"] = event.Connection{
+		Statistics: connectionstate.Statistics{BytesSent: 1, BytesReceived: 2},
+		Invoice:    crypto.Invoice{AgreementTotal: big.NewInt(10001)},
+		Latency:    45 * time.Millisecond,
+	}
 
 	req := httptest.NewRequest(http.MethodDelete, "/connection", nil)
 	resp := httptest.NewRecorder()
 
 	g := summonTestGin()
-	err := AddRoutesForConnection(&fakeManager, nil, &mockProposalRepository{}, mockIdentityRegistryInstance, eventbus.New(), &mockAddressProvider{})(g)
+	err := AddRoutesForConnection(&fakeManager, fakeState, &mockProposalRepository{}, mockIdentityRegistryInstance, eventbus.New(), &mockAddressProvider{})(g)
 	assert.NoError(t, err)
 
 	g.ServeHTTP(resp, req)
@@ -384,6 +415,24 @@ func TestDeleteCallsDisconnect(t *testing.T) {
 	assert.Equal(t, http.StatusAccepted, resp.Code)
 
 	assert.Equal(t, fakeManager.disconnectCount, 1)
+	assert.JSONEq(
+		t,
+		`{
+			"bytes_sent": 3,
+			"bytes_received": 4,
+			"throughput_sent": 0,
+			"throughput_received": 0,
+			"latency": 45,
+			"duration": 0,
+			"tokens_spent": 10001,
+			"spent_tokens": {
+				"ether": "0.000000000000010001",
+				"human": "0",
+				"wei": "10001"
+			}
+		}`,
+		resp.Body.String(),
+	)
 }
 
 func TestGetStatisticsEndpointReturnsStatistics(t *testing.T) {
@@ -392,6 +441,7 @@ func TestGetStatisticsEndpointReturnsStatistics(t *testing.T) {
 		Statistics: connectionstate.Statistics{BytesSent: 1, BytesReceived: 2},
 		Throughput: bandwidth.Throughput{Up: datasize.BitSpeed(1000), Down: datasize.BitSpeed(2000)},
 		Invoice:    crypto.Invoice{AgreementTotal: big.NewInt(10001)},
+		Latency:    45 * time.Millisecond,
 	}
 
 	manager := mockConnectionManager{}
@@ -422,6 +472,7 @@ func TestGetStatisticsEndpointReturnsStatistics(t *testing.T) {
 			"bytes_received": 2,
 			"throughput_sent": 1000,
 			"throughput_received": 2000,
+			"latency": 45,
 			"duration": 0,
 			"tokens_spent": 10001,
 			"spent_tokens": {
@@ -434,6 +485,194 @@ func TestGetStatisticsEndpointReturnsStatistics(t *testing.T) {
 	)
 }
 
+func TestPutWithForceReplacesExistingConnection(t *testing.T) {
+	fakeManager := mockConnectionManager{}
+	fakeState := &mockStateProvider{stateToReturn: event.State{Connections: make(map[string]event.Connection)}}
+
+	req := httptest.NewRequest(
+		http.MethodPut,
+		"/connection",
+		strings.NewReader(
+			`{
+				"consumer_id" : "my-identity",
+				"provider_id" : "required-node",
+				"hermes_id" : "hermes",
+				"force" : true
+			}`))
+	resp := httptest.NewRecorder()
+
+	g := summonTestGin()
+	err := AddRoutesForConnection(&fakeManager, fakeState, mockRepositoryWithProposal("required-node", "openvpn"), mockIdentityRegistryInstance, eventbus.New(), &mockAddressProvider{})(g)
+	assert.NoError(t, err)
+
+	g.ServeHTTP(resp, req)
+
+	assert.Equal(t, http.StatusCreated, resp.Code)
+	assert.Equal(t, 1, fakeManager.disconnectCount)
+	assert.Equal(t, identity.FromAddress("required-node"), fakeManager.requestedProvider)
+}
+
+func putIdempotentConnection(g http.Handler, key, body string) *httptest.ResponseRecorder {
+	req := httptest.NewRequest(http.MethodPut, "/connection", strings.NewReader(body))
+	req.Header.Set(IdempotencyKeyHeader, key)
+	resp := httptest.NewRecorder()
+	g.ServeHTTP(resp, req)
+	return resp
+}
+
+func TestPutWithSameIdempotencyKeyConnectsOnce(t *testing.T) {
+	fakeManager := mockConnectionManager{
+		onStatusReturn: connectionstate.Status{State: connectionstate.Connecting, SessionID: "1"},
+	}
+	fakeState := &mockStateProvider{stateToReturn: event.State{Connections: make(map[string]event.Connection)}}
+
+	g := summonTestGin()
+	err := AddRoutesForConnection(&fakeManager, fakeState, mockRepositoryWithProposal("required-node", "openvpn"), mockIdentityRegistryInstance, eventbus.New(), &mockAddressProvider{})(g)
+	assert.NoError(t, err)
+
+	body := `{"consumer_id": "my-identity", "provider_id": "required-node", "hermes_id": "hermes"}`
+
+	first := putIdempotentConnection(g, "key-1", body)
+	retried := putIdempotentConnection(g, "key-1", body)
+	assert.Equal(t, http.StatusCreated, first.Code)
+	assert.Equal(t, http.StatusCreated, retried.Code)
+	assert.JSONEq(t, first.Body.String(), retried.Body.String())
+	assert.Equal(t, 1, fakeManager.connectCount)
+
+	putIdempotentConnection(g, "key-2", body)
+	assert.Equal(t, 2, fakeManager.connectCount)
+
+	// The same key sent with another request is not a retry of it.
+	putIdempotentConnection(g, "key-2", `{"consumer_id": "my-identity", "provider_id": "required-node", "hermes_id": "hermes", "connect_options": {"proxy_port": 10000}}`)
+	assert.Equal(t, 3, fakeManager.connectCount)
+}
+
+func TestPutWithIdempotencyKeyRetriesFailedConnection(t *testing.T) {
+	fakeManager := mockConnectionManager{onConnectReturn: errors.New("boom")}
+	fakeState := &mockStateProvider{stateToReturn: event.State{Connections: make(map[string]event.Connection)}}
+
+	g := summonTestGin()
+	err := AddRoutesForConnection(&fakeManager, fakeState, mockRepositoryWithProposal("required-node", "openvpn"), mockIdentityRegistryInstance, eventbus.New(), &mockAddressProvider{})(g)
+	assert.NoError(t, err)
+
+	body := `{"consumer_id": "my-identity", "provider_id": "required-node", "hermes_id": "hermes"}`
+
+	resp := putIdempotentConnection(g, "key-1", body)
+	assert.Equal(t, http.StatusInternalServerError, resp.Code)
+
+	fakeManager.onConnectReturn = nil
+	resp = putIdempotentConnection(g, "key-1", body)
+	assert.Equal(t, http.StatusCreated, resp.Code)
+	assert.Equal(t, 2, fakeManager.connectCount)
+}
+
+func TestPutWithSameIdempotencyKeyConcurrentlyConnectsOnce(t *testing.T) {
+	connecting := make(chan struct{})
+	unblock := make(chan struct{})
+	fakeManager := mockConnectionManager{
+		onStatusReturn: connectionstate.Status{State: connectionstate.Connected, SessionID: "1"},
+		onConnect: func() {
+			close(connecting)
+			<-unblock
+		},
+	}
+	fakeState := &mockStateProvider{stateToReturn: event.State{Connections: make(map[string]event.Connection)}}
+
+	g := summonTestGin()
+	err := AddRoutesForConnection(&fakeManager, fakeState, mockRepositoryWithProposal("required-node", "openvpn"), mockIdentityRegistryInstance, eventbus.New(), &mockAddressProvider{})(g)
+	assert.NoError(t, err)
+
+	body := `{"consumer_id": "my-identity", "provider_id": "required-node", "hermes_id": "hermes"}`
+
+	const requests = 5
+	responses := make(chan *httptest.ResponseRecorder, requests)
+	for i := 0; i < requests; i++ {
+		go func() {
+			responses <- putIdempotentConnection(g, "key-1", body)
+		}()
+	}
+
+	<-connecting
+	// Let duplicates reach the reserved key before the first request finishes.
+	time.Sleep(50 * time.Millisecond)
+	close(unblock)
+
+	for i := 0; i < requests; i++ {
+		resp := <-responses
+		assert.Equal(t, http.StatusCreated, resp.Code)
+		assert.JSONEq(t, `{"status": "Connected", "session_id": "1"}`, resp.Body.String())
+	}
+	assert.Equal(t, 1, fakeManager.connectCount)
+}
+
+func TestGetConnectionsListsActiveConnections(t *testing.T) {
+	fakeState := &mockStateProvider{stateToReturn: event.State{Connections: make(map[string]event.Connection)}}
+	fakeState.stateToReturn.Connections["2"] = event.Connection{
+		Session: connectionstate.Status{State: connectionstate.Connecting, SessionID: "2", ProxyPort: 10000},
+	}
+	fakeState.stateToReturn.Connections["1"] = event.Connection{
+		Session: connectionstate.Status{State: connectionstate.Connected, SessionID: "1"},
+	}
+	fakeState.stateToReturn.Connections["3"] = event.Connection{
+		Session: connectionstate.Status{State: connectionstate.NotConnected, SessionID: "3", ProxyPort: 10001},
+	}
+
+	req := httptest.NewRequest(http.MethodGet, "/connections", nil)
+	resp := httptest.NewRecorder()
+
+	g := summonTestGin()
+	err := AddRoutesForConnection(&mockConnectionManager{}, fakeState, &mockProposalRepository{}, mockIdentityRegistryInstance, eventbus.New(), &mockAddressProvider{})(g)
+	assert.NoError(t, err)
+
+	g.ServeHTTP(resp, req)
+
+	assert.Equal(t, http.StatusOK, resp.Code)
+	assert.JSONEq(
+		t,
+		`{
+			"connections": [
+				{"status": "Connected", "session_id": "1", "proxy_port": 0},
+				{"status": "Connecting", "session_id": "2", "proxy_port": 10000}
+			]
+		}`,
+		resp.Body.String(),
+	)
+}
+
+func TestPutWithForceRejectsInvalidProxyPort(t *testing.T) {
+	for _, port := range []string{"-1", "65536"} {
+		t.Run(port, func(t *testing.T) {
+			fakeManager := mockConnectionManager{}
+
+			req := httptest.NewRequest(
+				http.MethodPut,
+				"/connection",
+				strings.NewReader(
+					`{
+						"consumer_id" : "my-identity",
+						"provider_id" : "required-node",
+						"hermes_id" : "hermes",
+						"connect_options": {"proxy_port": `+port+`},
+						"force" : true
+					}`))
+			resp := httptest.NewRecorder()
+
+			g := summonTestGin()
+			err := AddRoutesForConnection(&fakeManager, nil, mockRepositoryWithProposal("required-node", "openvpn"), mockIdentityRegistryInstance, eventbus.New(), &mockAddressProvider{})(g)
+			assert.NoError(t, err)
+
+			g.ServeHTTP(resp, req)
+
+			assert.Equal(t, http.StatusBadRequest, resp.Code)
+			apiErr := apierror.Parse(resp.Result())
+			assert.Equal(t, "validation_failed", apiErr.Err.Code)
+			assert.Equal(t, "invalid_value", apiErr.Err.Fields["proxy_port"].Code)
+			assert.Equal(t, 0, fakeManager.disconnectCount)
+			assert.Equal(t, 0, fakeManager.connectCount)
+		})
+	}
+}
+
 func TestEndpointReturnsConflictStatusIfConnectionAlreadyExists(t *testing.T) {
 	manager := mockConnectionManager{}
 	manager.onConnectReturn = connection.ErrAlreadyExists
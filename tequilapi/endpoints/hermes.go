@@ -0,0 +1,161 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package endpoints
+
+import (
+	"errors"
+	"time"
+
+	"github.com/ethereum/go-ethereum/common"
+	"github.com/gin-gonic/gin"
+	"github.com/mysteriumnetwork/go-rest/apierror"
+
+	"github.com/mysteriumnetwork/node/config"
+	"github.com/mysteriumnetwork/node/session/pingpong"
+	"github.com/mysteriumnetwork/node/tequilapi/contract"
+	"github.com/mysteriumnetwork/node/tequilapi/utils"
+)
+
+// hermesDataCacheTime is how long consumer and provider data fetched from Hermes is reused.
+const hermesDataCacheTime = time.Minute
+
+type hermesDataProvider interface {
+	GetConsumerData(chainID int64, id string, cacheTime time.Duration) (pingpong.HermesUserInfo, error)
+	GetProviderDataCached(chainID int64, id string, cacheTime time.Duration) (pingpong.HermesUserInfo, error)
+}
+
+type hermesEndpoint struct {
+	hermes hermesDataProvider
+}
+
+// NewHermesEndpoint creates and returns Hermes endpoint
+func NewHermesEndpoint(hermes hermesDataProvider) *hermesEndpoint {
+	return &hermesEndpoint{hermes: hermes}
+}
+
+// swagger:operation GET /hermes/consumer/{id} Hermes hermesConsumerData
+//
+//	---
+//	summary: Returns consumer data from Hermes
+//	description: Proxies consumer channel data from the configured Hermes, so clients don't need to reach Hermes directly
+//	parameters:
+//	- in: path
+//	  name: id
+//	  description: Consumer identity
+//	  type: string
+//	  required: true
+//	responses:
+//	  200:
+//	    description: Consumer data
+//	    schema:
+//	      "$ref": "#/definitions/HermesUserInfoDTO"
+//	  400:
+//	    description: Identity is not a valid address
+//	    schema:
+//	      "$ref": "#/definitions/APIError"
+//	  404:
+//	    description: Identity unknown to Hermes
+//	    schema:
+//	      "$ref": "#/definitions/APIError"
+//	  500:
+//	    description: Internal server error
+//	    schema:
+//	      "$ref": "#/definitions/APIError"
+func (h *hermesEndpoint) ConsumerData(c *gin.Context) {
+	id, ok := h.identityParam(c)
+	if !ok {
+		return
+	}
+
+	info, err := h.hermes.GetConsumerData(config.GetInt64(config.FlagChainID), id, hermesDataCacheTime)
+	h.respond(c, info, err)
+}
+
+// swagger:operation GET /hermes/provider/{id} Hermes hermesProviderData
+//
+//	---
+//	summary: Returns provider data from Hermes
+//	description: Proxies provider channel data from the configured Hermes, so clients don't need to reach Hermes directly
+//	parameters:
+//	- in: path
+//	  name: id
+//	  description: Provider identity
+//	  type: string
+//	  required: true
+//	responses:
+//	  200:
+//	    description: Provider data
+//	    schema:
+//	      "$ref": "#/definitions/HermesUserInfoDTO"
+//	  400:
+//	    description: Identity is not a valid address
+//	    schema:
+//	      "$ref": "#/definitions/APIError"
+//	  404:
+//	    description: Identity unknown to Hermes
+//	    schema:
+//	      "$ref": "#/definitions/APIError"
+//	  500:
+//	    description: Internal server error
+//	    schema:
+//	      "$ref": "#/definitions/APIError"
+func (h *hermesEndpoint) ProviderData(c *gin.Context) {
+	id, ok := h.identityParam(c)
+	if !ok {
+		return
+	}
+
+	info, err := h.hermes.GetProviderDataCached(config.GetInt64(config.FlagChainID), id, hermesDataCacheTime)
+	h.respond(c, info, err)
+}
+
+func (h *hermesEndpoint) identityParam(c *gin.Context) (string, bool) {
+	id := c.Param("id")
+	if !common.IsHexAddress(id) {
+		c.Error(apierror.BadRequestField("'id' is not a valid identity address", apierror.ValidateErrInvalidVal, "id"))
+		return "", false
+	}
+	return id, true
+}
+
+func (h *hermesEndpoint) respond(c *gin.Context, info pingpong.HermesUserInfo, err error) {
+	if errors.Is(err, pingpong.ErrHermesNotFound) {
+		c.Error(apierror.NotFound("Identity not found in Hermes"))
+		return
+	}
+	if err != nil {
+		c.Error(apierror.Internal("Could not get data from Hermes: "+err.Error(), contract.ErrCodeHermesUserData))
+		return
+	}
+
+	utils.WriteAsJSON(contract.NewHermesUserInfoDTO(info), c.Writer)
+}
+
+// AddRoutesForHermes attaches Hermes endpoints to router
+func AddRoutesForHermes(hermes hermesDataProvider) func(*gin.Engine) error {
+	h := NewHermesEndpoint(hermes)
+
+	return func(e *gin.Engine) error {
+		hermesGroup := e.Group("/hermes")
+		{
+			hermesGroup.GET("/consumer/:id", h.ConsumerData)
+			hermesGroup.GET("/provider/:id", h.ProviderData)
+		}
+		return nil
+	}
+}
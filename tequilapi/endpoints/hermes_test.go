@@ -0,0 +1,100 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package endpoints
+
+import (
+	"math/big"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/mysteriumnetwork/go-rest/apierror"
+	"github.com/mysteriumnetwork/node/session/pingpong"
+)
+
+const (
+	hermesTestConsumer = "0x0000000000000000000000000000000000000001"
+	hermesTestUnknown  = "0x0000000000000000000000000000000000000002"
+	hermesTestProvider = "0x0000000000000000000000000000000000000003"
+)
+
+type mockHermesDataProvider struct {
+	consumerCacheTime time.Duration
+	providerCacheTime time.Duration
+}
+
+func (m *mockHermesDataProvider) GetConsumerData(_ int64, id string, cacheTime time.Duration) (pingpong.HermesUserInfo, error) {
+	m.consumerCacheTime = cacheTime
+	if id != hermesTestConsumer {
+		return pingpong.HermesUserInfo{}, pingpong.ErrHermesNotFound
+	}
+	return pingpong.HermesUserInfo{Identity: id, Balance: big.NewInt(100)}, nil
+}
+
+func (m *mockHermesDataProvider) GetProviderDataCached(_ int64, id string, cacheTime time.Duration) (pingpong.HermesUserInfo, error) {
+	m.providerCacheTime = cacheTime
+	return pingpong.HermesUserInfo{
+		Identity:      id,
+		LatestPromise: pingpong.LatestPromise{Amount: big.NewInt(7)},
+	}, nil
+}
+
+func TestHermesEndpoints(t *testing.T) {
+	hermes := &mockHermesDataProvider{}
+	g := summonTestGin()
+	err := AddRoutesForHermes(hermes)(g)
+	assert.NoError(t, err)
+
+	get := func(path string) *httptest.ResponseRecorder {
+		resp := httptest.NewRecorder()
+		g.ServeHTTP(resp, httptest.NewRequest(http.MethodGet, path, nil))
+		return resp
+	}
+
+	resp := get("/hermes/consumer/" + hermesTestConsumer)
+	assert.Equal(t, http.StatusOK, resp.Code)
+	assert.Contains(t, resp.Body.String(), `"identity":"`+hermesTestConsumer+`"`)
+	assert.Contains(t, resp.Body.String(), `"balance":100`)
+	assert.Equal(t, hermesDataCacheTime, hermes.consumerCacheTime)
+
+	resp = get("/hermes/consumer/" + hermesTestUnknown)
+	assert.Equal(t, http.StatusNotFound, resp.Code)
+
+	resp = get("/hermes/provider/" + hermesTestProvider)
+	assert.Equal(t, http.StatusOK, resp.Code)
+	assert.Contains(t, resp.Body.String(), `"latest_promise_amount":7`)
+	assert.Equal(t, hermesDataCacheTime, hermes.providerCacheTime)
+}
+
+func TestHermesEndpointsRejectInvalidIdentity(t *testing.T) {
+	g := summonTestGin()
+	err := AddRoutesForHermes(&mockHermesDataProvider{})(g)
+	assert.NoError(t, err)
+
+	for _, path := range []string{"/hermes/consumer/0x1", "/hermes/provider/not-an-address"} {
+		resp := httptest.NewRecorder()
+		g.ServeHTTP(resp, httptest.NewRequest(http.MethodGet, path, nil))
+
+		assert.Equal(t, http.StatusBadRequest, resp.Code, path)
+		apiErr := apierror.Parse(resp.Result())
+		assert.Equal(t, "invalid_value", apiErr.Err.Fields["id"].Code, path)
+	}
+}
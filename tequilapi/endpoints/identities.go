@@ -22,6 +22,7 @@ import (
 	"fmt"
 	"math/big"
 	"net/http"
+	"time"
 
 	"github.com/ethereum/go-ethereum/common"
 	"github.com/gin-gonic/gin"
@@ -35,6 +36,7 @@ import (
 	"github.com/mysteriumnetwork/node/identity"
 	"github.com/mysteriumnetwork/node/identity/registry"
 	identity_selector "github.com/mysteriumnetwork/node/identity/selector"
+	"github.com/mysteriumnetwork/node/session/pingpong"
 	pingpong_event "github.com/mysteriumnetwork/node/session/pingpong/event"
 	"github.com/mysteriumnetwork/node/tequilapi/contract"
 	"github.com/mysteriumnetwork/node/tequilapi/middlewares"
@@ -59,6 +61,14 @@ type providerChannel interface {
 	GetProviderChannel(chainID int64, hermesAddress common.Address, provider common.Address, pending bool) (client.ProviderChannel, error)
 }
 
+type consumerDataProvider interface {
+	GetConsumerData(chainID int64, id string, cacheTime time.Duration) (pingpong.HermesUserInfo, error)
+}
+
+type mystBalanceProvider interface {
+	GetMystBalance(chainID int64, mystAddress, identity common.Address) (*big.Int, error)
+}
+
 type identityMover interface {
 	Import(blob []byte, currPass, newPass string) (identity.Identity, error)
 	Export(address, currPass, newPass string) ([]byte, error)
@@ -77,12 +87,23 @@ type identitiesAPI struct {
 	bprovider          beneficiaryProvider
 	beneficiaryStorage beneficiary.BeneficiaryStorage
 	hermesMigrator     *migration.HermesMigrator
+	stateProvider      stateProvider
+	hermes             consumerDataProvider
+	mystBalance        mystBalanceProvider
 }
 
+const (
+	passphraseAttemptInterval = time.Second
+	passphraseAttemptBurst    = 10
+
+	consumerDataCacheTime = 10 * time.Second
+)
+
 // AddressProvider provides sc addresses.
 type AddressProvider interface {
 	GetActiveHermes(chainID int64) (common.Address, error)
 	GetActiveChannelAddress(chainID int64, id common.Address) (common.Address, error)
+	GetMystAddress(chainID int64) (common.Address, error)
 	GetKnownHermeses(chainID int64) ([]common.Address, error)
 	GetHermesChannelAddress(chainID int64, id, hermesAddr common.Address) (common.Address, error)
 }
@@ -91,7 +112,7 @@ type AddressProvider interface {
 //
 //	---
 //	summary: Returns identities
-//	description: Returns list of identities
+//	description: Returns list of identities with their default flag and registration status
 //	responses:
 //	  200:
 //	    description: List of identities
@@ -100,6 +121,27 @@ type AddressProvider interface {
 func (ia *identitiesAPI) List(c *gin.Context) {
 	ids := ia.idm.GetIdentities()
 	idsDTO := contract.NewIdentityListResponse(ids)
+
+	var defaultAddress string
+	if defaultID, err := ia.selector.GetDefault(); err == nil {
+		defaultAddress = defaultID.Address
+	}
+
+	// Registration status is taken from the state, as asking the registry may go to blockchain for every identity.
+	statuses := make(map[string]registry.RegistrationStatus)
+	for _, id := range ia.stateProvider.GetState().Identities {
+		statuses[id.Address] = id.RegistrationStatus
+	}
+
+	for i := range idsDTO.Identities {
+		id := &idsDTO.Identities[i]
+		id.Default = id.Address == defaultAddress
+		status, ok := statuses[id.Address]
+		if !ok {
+			status = registry.Unknown
+		}
+		id.RegistrationStatus = status.String()
+	}
 	utils.WriteAsJSON(idsDTO, c.Writer)
 }
 
@@ -309,6 +351,84 @@ func (ia *identitiesAPI) Unlock(c *gin.Context) {
 	c.Status(http.StatusAccepted)
 }
 
+// swagger:operation GET /identities/{id}/balance Identity getBalance
+//
+//	---
+//	summary: Balance of given identity
+//	description: Returns consumer balance of given identity in Hermes and, optionally, on-chain balance of its channel on the configured chain
+//	parameters:
+//	  - in: path
+//	    name: id
+//	    description: hex address of identity
+//	    type: string
+//	    required: true
+//	  - in: query
+//	    name: onchain
+//	    description: include on-chain balance of identity channel
+//	    type: boolean
+//	responses:
+//	  200:
+//	    description: Balance
+//	    schema:
+//	      "$ref": "#/definitions/IdentityBalanceDTO"
+//	  404:
+//	    description: ID not found
+//	    schema:
+//	      "$ref": "#/definitions/APIError"
+//	  500:
+//	    description: Internal server error
+//	    schema:
+//	      "$ref": "#/definitions/APIError"
+func (ia *identitiesAPI) Balance(c *gin.Context) {
+	address := c.Param("id")
+	id, err := ia.idm.GetIdentity(address)
+	if err != nil {
+		c.Error(apierror.NotFound("Identity not found"))
+		return
+	}
+
+	chainID := config.GetInt64(config.FlagChainID)
+	// Identity unknown to Hermes has no consumer channel yet, so it has nothing to spend.
+	consumer, err := ia.hermes.GetConsumerData(chainID, id.Address, consumerDataCacheTime)
+	if errors.Is(err, pingpong.ErrHermesNotFound) {
+		consumer, err = pingpong.HermesUserInfo{Balance: new(big.Int)}, nil
+	}
+	if err != nil {
+		c.Error(apierror.Internal("Failed to get consumer balance from Hermes: "+err.Error(), contract.ErrCodeIDBalanceHermes))
+		return
+	}
+
+	resp := contract.IdentityBalanceDTO{
+		Hermes: contract.NewBalanceDTO(consumer.Balance),
+	}
+
+	if c.Query("onchain") == "true" {
+		balance, err := ia.onchainBalance(chainID, id)
+		if err != nil {
+			c.Error(apierror.Internal("Failed to get on-chain balance: "+err.Error(), contract.ErrCodeIDBalanceOnchain))
+			return
+		}
+		onchain := contract.NewBalanceDTO(balance)
+		resp.Onchain = &onchain
+	}
+
+	utils.WriteAsJSON(resp, c.Writer)
+}
+
+func (ia *identitiesAPI) onchainBalance(chainID int64, id identity.Identity) (*big.Int, error) {
+	channel, err := ia.addressProvider.GetActiveChannelAddress(chainID, id.ToCommonAddress())
+	if err != nil {
+		return nil, err
+	}
+
+	myst, err := ia.addressProvider.GetMystAddress(chainID)
+	if err != nil {
+		return nil, err
+	}
+
+	return ia.mystBalance.GetMystBalance(chainID, myst, channel)
+}
+
 // swagger:operation PUT /identities/{id}/balance/refresh Identity balance
 //
 //	---
@@ -767,6 +887,9 @@ func AddRoutesForIdentities(
 	mover identityMover,
 	addressStorage beneficiary.BeneficiaryStorage,
 	hermesMigrator *migration.HermesMigrator,
+	stateProvider stateProvider,
+	hermes consumerDataProvider,
+	mystBalance mystBalanceProvider,
 ) func(*gin.Engine) error {
 	idAPI := &identitiesAPI{
 		mover:              mover,
@@ -781,7 +904,12 @@ func AddRoutesForIdentities(
 		bprovider:          bprovider,
 		beneficiaryStorage: addressStorage,
 		hermesMigrator:     hermesMigrator,
+		stateProvider:      stateProvider,
+		hermes:             hermes,
+		mystBalance:        mystBalance,
 	}
+	// Limits passphrase guessing against the keystore.
+	passphraseLimiter := middlewares.NewRateLimiter(passphraseAttemptInterval, passphraseAttemptBurst)
 	return func(e *gin.Engine) error {
 		identityGroup := e.Group("/identities")
 		{
@@ -790,18 +918,19 @@ func AddRoutesForIdentities(
 			identityGroup.PUT("/current", idAPI.Current)
 			identityGroup.GET("/:id", idAPI.Get)
 			identityGroup.GET("/:id/status", idAPI.Get)
-			identityGroup.PUT("/:id/unlock", idAPI.Unlock)
+			identityGroup.PUT("/:id/unlock", passphraseLimiter, idAPI.Unlock)
 			identityGroup.GET("/:id/registration", idAPI.RegistrationStatus)
 			identityGroup.GET("/:id/beneficiary", idAPI.Beneficiary)
 			identityGroup.GET("/:id/beneficiary-async", idAPI.GetBeneficiaryAddressAsync)
 			identityGroup.POST("/:id/beneficiary-async", idAPI.SaveBeneficiaryAddressAsync)
+			identityGroup.GET("/:id/balance", idAPI.Balance)
 			identityGroup.PUT("/:id/balance/refresh", idAPI.BalanceRefresh)
 			identityGroup.POST("/:id/migrate-hermes", idAPI.MigrateHermes)
 			identityGroup.GET("/:id/migrate-hermes/status", idAPI.MigrationHermesStatus)
 			identityGroup.POST("/export", middlewares.NewLocalhostOnlyFilter(), idAPI.Export)
 
 		}
-		e.POST("/identities-import", idAPI.Import)
+		e.POST("/identities-import", passphraseLimiter, idAPI.Import)
 		return nil
 	}
 }
@@ -24,10 +24,12 @@ import (
 	"net/http"
 	"net/http/httptest"
 	"testing"
+	"time"
 
 	"github.com/ethereum/go-ethereum/common"
 	"github.com/gin-gonic/gin"
 	"github.com/mysteriumnetwork/go-rest/apierror"
+	stateEvent "github.com/mysteriumnetwork/node/core/state/event"
 	"github.com/mysteriumnetwork/node/identity"
 	"github.com/mysteriumnetwork/node/identity/registry"
 	"github.com/mysteriumnetwork/node/session/pingpong"
@@ -75,6 +77,10 @@ func (hf *selectorFake) SetDefault(address string) error {
 	return nil
 }
 
+func (hf *selectorFake) GetDefault() (identity.Identity, error) {
+	return identity.Identity{Address: "0x000000000000000000000000000000000000beef"}, nil
+}
+
 func TestCurrentIdentitySuccess(t *testing.T) {
 	mockIdm := identity.NewIdentityManagerFake(existingIdentities, newIdentity)
 	resp := httptest.NewRecorder()
@@ -376,7 +382,15 @@ func TestListIdentities(t *testing.T) {
 	req := httptest.NewRequest("GET", path, nil)
 	resp := httptest.NewRecorder()
 
-	endpoint := &identitiesAPI{idm: mockIdm}
+	endpoint := &identitiesAPI{
+		idm:      mockIdm,
+		selector: &selectorFake{},
+		stateProvider: &mockStateProvider{stateToReturn: stateEvent.State{
+			Identities: []stateEvent.Identity{
+				{Address: "0x000000000000000000000000000000000000000a", RegistrationStatus: registry.Registered},
+			},
+		}},
+	}
 	g := summonTestGin()
 	g.GET(path, endpoint.List)
 
@@ -386,14 +400,89 @@ func TestListIdentities(t *testing.T) {
 		t,
 		`{
             "identities": [
-                {"id": "0x000000000000000000000000000000000000000a"},
-                {"id": "0x000000000000000000000000000000000000beef"}
+                {"id": "0x000000000000000000000000000000000000000a", "registration_status": "Registered"},
+                {"id": "0x000000000000000000000000000000000000beef", "default": true, "registration_status": "Unknown"}
             ]
         }`,
 		resp.Body.String(),
 	)
 }
 
+func Test_IdentityBalance(t *testing.T) {
+	endpoint := &identitiesAPI{
+		idm:             identity.NewIdentityManagerFake(existingIdentities, newIdentity),
+		addressProvider: &mockAddressProvider{},
+		hermes:          &mockConsumerDataProvider{info: pingpong.HermesUserInfo{Balance: big.NewInt(25)}},
+		mystBalance:     &mockMystBalanceProvider{balance: big.NewInt(1500000000000000000)},
+	}
+
+	g := summonTestGin()
+	g.GET("/identities/:id/balance", endpoint.Balance)
+
+	resp := httptest.NewRecorder()
+	req := httptest.NewRequest(http.MethodGet, "/identities/0x000000000000000000000000000000000000000a/balance", nil)
+	g.ServeHTTP(resp, req)
+
+	assert.Equal(t, http.StatusOK, resp.Code)
+	assert.JSONEq(t, `{
+		"hermes": {
+			"balance": 25,
+			"balance_tokens": {
+				"wei": "25",
+				"ether": "0.000000000000000025",
+				"human": "0"
+			}
+		}
+	}`, resp.Body.String())
+
+	resp = httptest.NewRecorder()
+	req = httptest.NewRequest(http.MethodGet, "/identities/0x000000000000000000000000000000000000000a/balance?onchain=true", nil)
+	g.ServeHTTP(resp, req)
+
+	assert.Equal(t, http.StatusOK, resp.Code)
+	assert.JSONEq(t, `{
+		"hermes": {
+			"balance": 25,
+			"balance_tokens": {
+				"wei": "25",
+				"ether": "0.000000000000000025",
+				"human": "0"
+			}
+		},
+		"onchain": {
+			"balance": 1500000000000000000,
+			"balance_tokens": {
+				"wei": "1500000000000000000",
+				"ether": "1.5",
+				"human": "1.5"
+			}
+		}
+	}`, resp.Body.String())
+
+	resp = httptest.NewRecorder()
+	req = httptest.NewRequest(http.MethodGet, "/identities/0x00000000000000000000000000000000000000ff/balance", nil)
+	g.ServeHTTP(resp, req)
+
+	assert.Equal(t, http.StatusNotFound, resp.Code)
+}
+
+func Test_IdentityBalance_UnknownToHermes(t *testing.T) {
+	endpoint := &identitiesAPI{
+		idm:    identity.NewIdentityManagerFake(existingIdentities, newIdentity),
+		hermes: &mockConsumerDataProvider{err: pingpong.ErrHermesNotFound},
+	}
+
+	g := summonTestGin()
+	g.GET("/identities/:id/balance", endpoint.Balance)
+
+	resp := httptest.NewRecorder()
+	req := httptest.NewRequest(http.MethodGet, "/identities/0x000000000000000000000000000000000000000a/balance", nil)
+	g.ServeHTTP(resp, req)
+
+	assert.Equal(t, http.StatusOK, resp.Code)
+	assert.JSONEq(t, `{"hermes": {"balance": 0, "balance_tokens": {"wei": "0", "ether": "0", "human": "0"}}}`, resp.Body.String())
+}
+
 func Test_IdentityGet(t *testing.T) {
 	endpoint := &identitiesAPI{
 		idm:      identity.NewIdentityManagerFake(existingIdentities, newIdentity),
@@ -548,6 +637,23 @@ func (mep *mockEarningsProvider) GetEarningsDetailed(chainID int64, _ identity.I
 	return &mep.earnings
 }
 
+type mockConsumerDataProvider struct {
+	info pingpong.HermesUserInfo
+	err  error
+}
+
+func (m *mockConsumerDataProvider) GetConsumerData(_ int64, _ string, _ time.Duration) (pingpong.HermesUserInfo, error) {
+	return m.info, m.err
+}
+
+type mockMystBalanceProvider struct {
+	balance *big.Int
+}
+
+func (m *mockMystBalanceProvider) GetMystBalance(_ int64, _, _ common.Address) (*big.Int, error) {
+	return m.balance, nil
+}
+
 type mockBalanceProvider struct {
 	balance            *big.Int
 	forceUpdateBalance *big.Int
@@ -0,0 +1,96 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package endpoints
+
+import (
+	"errors"
+	"net/http"
+	"time"
+
+	"github.com/gin-gonic/gin"
+	"github.com/rs/zerolog/log"
+
+	"github.com/mysteriumnetwork/go-rest/apierror"
+	"github.com/mysteriumnetwork/node/config"
+	"github.com/mysteriumnetwork/node/identity"
+	"github.com/mysteriumnetwork/node/session/pingpong"
+)
+
+type unlockedIdentityProvider interface {
+	GetUnlockedIdentity() (identity.Identity, bool)
+}
+
+// readinessCacheTime is how long Hermes reachability is reused, so frequent probes don't flood Hermes.
+const readinessCacheTime = time.Minute
+
+type probesEndpoint struct {
+	identities unlockedIdentityProvider
+	hermes     consumerDataProvider
+}
+
+// AddRoutesForProbes adds liveness and readiness probe routes to given router
+func AddRoutesForProbes(identities unlockedIdentityProvider, hermes consumerDataProvider) func(*gin.Engine) error {
+	endpoint := &probesEndpoint{identities: identities, hermes: hermes}
+	return func(e *gin.Engine) error {
+		e.GET("/livez", endpoint.Livez)
+		e.GET("/readyz", endpoint.Readyz)
+		return nil
+	}
+}
+
+// swagger:operation GET /livez Client livenessProbe
+//
+//	---
+//	summary: Liveness probe
+//	description: Responds as long as the node process is able to serve requests
+//	responses:
+//	  200:
+//	    description: Node is alive
+func (pe *probesEndpoint) Livez(c *gin.Context) {
+	c.Status(http.StatusOK)
+}
+
+// swagger:operation GET /readyz Client readinessProbe
+//
+//	---
+//	summary: Readiness probe
+//	description: Responds successfully once the node has an unlocked identity and Hermes is reachable. Being served on the API listener, any response also means the listener is bound
+//	responses:
+//	  200:
+//	    description: Node is ready
+//	  503:
+//	    description: Node is not ready yet
+//	    schema:
+//	      "$ref": "#/definitions/APIError"
+func (pe *probesEndpoint) Readyz(c *gin.Context) {
+	id, ok := pe.identities.GetUnlockedIdentity()
+	if !ok {
+		c.Error(apierror.ServiceUnavailable())
+		return
+	}
+
+	// Identity being unknown to Hermes still means Hermes is reachable.
+	_, err := pe.hermes.GetConsumerData(config.GetInt64(config.FlagChainID), id.Address, readinessCacheTime)
+	if err != nil && !errors.Is(err, pingpong.ErrHermesNotFound) {
+		log.Warn().Err(err).Msg("Readiness probe failed, Hermes is not reachable")
+		c.Error(apierror.ServiceUnavailable())
+		return
+	}
+
+	c.Status(http.StatusOK)
+}
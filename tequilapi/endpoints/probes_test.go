@@ -0,0 +1,66 @@
+/*
+ * Copyright (C) 2026 The "MysteriumNetwork/node" Authors.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+package endpoints
+
+import (
+	"errors"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+
+	"github.com/mysteriumnetwork/node/identity"
+	"github.com/mysteriumnetwork/node/session/pingpong"
+)
+
+type mockUnlockedIdentityProvider struct {
+	unlocked bool
+}
+
+func (m *mockUnlockedIdentityProvider) GetUnlockedIdentity() (identity.Identity, bool) {
+	return identity.Identity{Address: "0x1"}, m.unlocked
+}
+
+func TestProbes(t *testing.T) {
+	identities := &mockUnlockedIdentityProvider{}
+	hermes := &mockConsumerDataProvider{}
+	g := summonTestGin()
+	err := AddRoutesForProbes(identities, hermes)(g)
+	assert.NoError(t, err)
+
+	probe := func(path string) int {
+		resp := httptest.NewRecorder()
+		g.ServeHTTP(resp, httptest.NewRequest(http.MethodGet, path, nil))
+		return resp.Code
+	}
+
+	assert.Equal(t, http.StatusOK, probe("/livez"))
+	assert.Equal(t, http.StatusServiceUnavailable, probe("/readyz"))
+
+	identities.unlocked = true
+	assert.Equal(t, http.StatusOK, probe("/readyz"))
+
+	hermes.err = errors.New("could not execute request: connection refused")
+	assert.Equal(t, http.StatusServiceUnavailable, probe("/readyz"))
+	assert.Equal(t, http.StatusOK, probe("/livez"))
+
+	hermes.err = fmt.Errorf("could not request consumer data from hermes: %w", pingpong.ErrHermesNotFound)
+	assert.Equal(t, http.StatusOK, probe("/readyz"))
+}
@@ -18,6 +18,7 @@
 package endpoints
 
 import (
+	"math/big"
 	"strconv"
 
 	"github.com/gin-gonic/gin"
@@ -112,11 +113,23 @@ func NewProposalsEndpoint(proposalRepository proposalRepository, pricer priceAPI
 //	    name: nat_compatibility
 //	    description: Pick nodes compatible with NAT of specified type. Specify "auto" to probe NAT.
 //	    type: string
+//	  - in: query
+//	    name: price_hour_max
+//	    description: Maximum price per hour of the proposal in wei.
+//	    type: string
+//	  - in: query
+//	    name: price_gib_max
+//	    description: Maximum price per GiB of the proposal in wei.
+//	    type: string
 //	responses:
 //	  200:
 //	    description: List of proposals
 //	    schema:
 //	      "$ref": "#/definitions/ListProposalsResponse"
+//	  400:
+//	    description: Failed to parse or request validation failed
+//	    schema:
+//	      "$ref": "#/definitions/APIError"
 //	  500:
 //	    description: Internal server error
 //	    schema:
@@ -148,6 +161,17 @@ func (pe *proposalsEndpoint) List(c *gin.Context) {
 		}
 	}
 
+	priceHourMax, ok := parsePriceLimit(req.URL.Query().Get("price_hour_max"))
+	if !ok {
+		c.Error(apierror.BadRequestField("'price_hour_max' is invalid", apierror.ValidateErrInvalidVal, "price_hour_max"))
+		return
+	}
+	priceGiBMax, ok := parsePriceLimit(req.URL.Query().Get("price_gib_max"))
+	if !ok {
+		c.Error(apierror.BadRequestField("'price_gib_max' is invalid", apierror.ValidateErrInvalidVal, "price_gib_max"))
+		return
+	}
+
 	includeMonitoringFailed, _ := strconv.ParseBool(req.URL.Query().Get("include_monitoring_failed"))
 	proposals, err := pe.proposalRepository.Proposals(&proposal.Filter{
 		PresetID:                presetID,
@@ -163,6 +187,8 @@ func (pe *proposalsEndpoint) List(c *gin.Context) {
 		QualityMin:              qualityMin,
 		ExcludeUnsupported:      true,
 		IncludeMonitoringFailed: includeMonitoringFailed,
+		PriceHourMax:            priceHourMax,
+		PriceGiBMax:             priceGiBMax,
 	})
 	if err != nil {
 		c.Error(apierror.Internal("Proposal query failed: "+err.Error(), contract.ErrCodeProposalsQuery))
@@ -177,6 +203,19 @@ func (pe *proposalsEndpoint) List(c *gin.Context) {
 	utils.WriteAsJSON(proposalsRes, c.Writer)
 }
 
+// parsePriceLimit parses price limit given in wei, returns nil if no limit is given
+// and false if the given limit is not a non-negative integer.
+func parsePriceLimit(value string) (*big.Int, bool) {
+	if value == "" {
+		return nil, true
+	}
+	limit, ok := new(big.Int).SetString(value, 10)
+	if !ok || limit.Sign() < 0 {
+		return nil, false
+	}
+	return limit, true
+}
+
 // swagger:operation GET /proposals/countries Countries listCountries
 //
 //	---
@@ -30,6 +30,7 @@ import (
 	"github.com/stretchr/testify/assert"
 	"golang.org/x/net/context"
 
+	"github.com/mysteriumnetwork/go-rest/apierror"
 	"github.com/mysteriumnetwork/node/core/discovery/proposal"
 	"github.com/mysteriumnetwork/node/core/location/locationstate"
 	"github.com/mysteriumnetwork/node/market"
@@ -244,6 +245,57 @@ func TestProposalsEndpointAcceptsAccessPolicyParams(t *testing.T) {
 	)
 }
 
+func TestProposalsEndpointFilterByPrice(t *testing.T) {
+	repository := &mockProposalRepository{
+		proposals: serviceProposals,
+	}
+
+	path := "/proposals"
+	req, err := http.NewRequest(
+		http.MethodGet,
+		path,
+		nil,
+	)
+	assert.Nil(t, err)
+
+	query := req.URL.Query()
+	setPricingBounds(query)
+	req.URL.RawQuery = query.Encode()
+
+	resp := httptest.NewRecorder()
+	endpoint := NewProposalsEndpoint(repository, nil, nil, &mockFilterPresetRepository{}, mockedNATProber)
+	g := gin.Default()
+	g.GET(path, endpoint.List)
+	g.ServeHTTP(resp, req)
+
+	assert.Equal(t, http.StatusOK, resp.Code)
+	assert.Equal(t, priceHourMax, repository.recordedFilter.PriceHourMax)
+	assert.Equal(t, priceGiBMax, repository.recordedFilter.PriceGiBMax)
+}
+
+func TestProposalsEndpointRejectsInvalidPriceLimit(t *testing.T) {
+	for _, param := range []string{"price_hour_max", "price_gib_max"} {
+		for _, value := range []string{"cheap", "1.5", "-1"} {
+			t.Run(param+"="+value, func(t *testing.T) {
+				req := httptest.NewRequest(http.MethodGet, "/proposals?"+param+"="+value, nil)
+				resp := httptest.NewRecorder()
+
+				repository := &mockProposalRepository{proposals: serviceProposals}
+				endpoint := NewProposalsEndpoint(repository, nil, nil, &mockFilterPresetRepository{}, mockedNATProber)
+				g := summonTestGin()
+				g.GET("/proposals", endpoint.List)
+				g.ServeHTTP(resp, req)
+
+				assert.Equal(t, http.StatusBadRequest, resp.Code)
+				apiErr := apierror.Parse(resp.Result())
+				assert.Equal(t, "validation_failed", apiErr.Err.Code)
+				assert.Contains(t, apiErr.Err.Fields, param)
+				assert.Equal(t, "invalid_value", apiErr.Err.Fields[param].Code)
+			})
+		}
+	}
+}
+
 func TestCurrentPrices(t *testing.T) {
 	// given
 	repository := &mockProposalRepository{
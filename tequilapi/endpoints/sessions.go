@@ -18,6 +18,7 @@
 package endpoints
 
 import (
+	"net/http"
 	"time"
 
 	"github.com/gin-gonic/gin"
@@ -25,6 +26,7 @@ import (
 	"github.com/go-openapi/strfmt/conv"
 	"github.com/mysteriumnetwork/go-rest/apierror"
 	"github.com/mysteriumnetwork/node/consumer/session"
+	node_session "github.com/mysteriumnetwork/node/session"
 	"github.com/mysteriumnetwork/node/tequilapi/contract"
 	"github.com/mysteriumnetwork/node/tequilapi/utils"
 	"github.com/vcraescu/go-paginator/adapter"
@@ -36,8 +38,13 @@ type sessionStorage interface {
 	StatsByDay(*session.Filter) (map[time.Time]session.Stats, error)
 }
 
+type sessionKiller interface {
+	Kill(id node_session.ID) bool
+}
+
 type sessionsEndpoint struct {
 	sessionStorage sessionStorage
+	sessionKiller  sessionKiller
 }
 
 // NewSessionsEndpoint creates and returns sessions endpoint
@@ -169,6 +176,33 @@ func (endpoint *sessionsEndpoint) StatsDaily(c *gin.Context) {
 	utils.WriteAsJSON(sessionsDTO, c.Writer)
 }
 
+// swagger:operation DELETE /sessions/{id} Session sessionKill
+//
+//	---
+//	summary: Terminates an active provider session
+//	description: Closes the given session on the provider side, disconnecting the consumer
+//	parameters:
+//	- in: path
+//	  name: id
+//	  description: Session ID
+//	  type: string
+//	  required: true
+//	responses:
+//	  202:
+//	    description: Session terminated
+//	  404:
+//	    description: Session not found
+//	    schema:
+//	      "$ref": "#/definitions/APIError"
+func (endpoint *sessionsEndpoint) Kill(c *gin.Context) {
+	if !endpoint.sessionKiller.Kill(node_session.ID(c.Param("id"))) {
+		c.Error(apierror.NotFound("Session not found"))
+		return
+	}
+
+	c.Status(http.StatusAccepted)
+}
+
 // AddRoutesForSessions attaches sessions endpoints to router
 func AddRoutesForSessions(sessionStorage sessionStorage) func(*gin.Engine) error {
 	sessionsEndpoint := NewSessionsEndpoint(sessionStorage)
@@ -182,3 +216,12 @@ func AddRoutesForSessions(sessionStorage sessionStorage) func(*gin.Engine) error
 		return nil
 	}
 }
+
+// AddRoutesForSessionKill attaches active provider session termination endpoint to router
+func AddRoutesForSessionKill(sessionKiller sessionKiller) func(*gin.Engine) error {
+	sessionsEndpoint := &sessionsEndpoint{sessionKiller: sessionKiller}
+	return func(e *gin.Engine) error {
+		e.DELETE("/sessions/:id", sessionsEndpoint.Kill)
+		return nil
+	}
+}
@@ -30,8 +30,12 @@ import (
 	"github.com/stretchr/testify/assert"
 
 	"github.com/mysteriumnetwork/node/consumer/session"
+	"github.com/mysteriumnetwork/node/core/service"
 	"github.com/mysteriumnetwork/node/identity"
+	"github.com/mysteriumnetwork/node/mocks"
+	"github.com/mysteriumnetwork/node/pb"
 	node_session "github.com/mysteriumnetwork/node/session"
+	"github.com/mysteriumnetwork/node/trace"
 )
 
 var (
@@ -246,6 +250,40 @@ func Test_SessionsEndpoint_StatsDaily(t *testing.T) {
 	assert.Equal(t, time.Now().UTC().Day(), ssm.calledWithFilter.StartedTo.Day())
 }
 
+func Test_SessionsEndpoint_Kill(t *testing.T) {
+	active, err := service.NewSession(&service.Instance{}, &pb.SessionRequest{}, trace.NewTracer(""))
+	assert.NoError(t, err)
+	pool := service.NewSessionPool(mocks.NewEventBus())
+	pool.Add(active)
+
+	g := summonTestGin()
+	err = AddRoutesForSessionKill(pool)(g)
+	assert.NoError(t, err)
+
+	req, _ := http.NewRequest(http.MethodDelete, "/sessions/"+string(active.ID), nil)
+	resp := httptest.NewRecorder()
+	g.ServeHTTP(resp, req)
+
+	assert.Equal(t, http.StatusAccepted, resp.Code)
+	select {
+	case <-active.Done():
+	default:
+		t.Error("session was not closed")
+	}
+}
+
+func Test_SessionsEndpoint_KillUnknown(t *testing.T) {
+	g := summonTestGin()
+	err := AddRoutesForSessionKill(service.NewSessionPool(mocks.NewEventBus()))(g)
+	assert.NoError(t, err)
+
+	req, _ := http.NewRequest(http.MethodDelete, "/sessions/unknown", nil)
+	resp := httptest.NewRecorder()
+	g.ServeHTTP(resp, req)
+
+	assert.Equal(t, http.StatusNotFound, resp.Code)
+}
+
 type sessionStorageMock struct {
 	sessionsToReturn   []session.History
 	statsToReturn      session.Stats
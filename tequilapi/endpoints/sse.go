@@ -38,5 +38,6 @@ func AddRoutesForSSE(e *gin.Engine, stateProvider stateProvider, bus eventbus.Ev
 		return err
 	}
 	e.GET("/events/state", sseHandler.Sub)
+	e.GET("/state", sseHandler.Snapshot)
 	return nil
 }
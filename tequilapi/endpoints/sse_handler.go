@@ -36,6 +36,7 @@ import (
 	"github.com/mysteriumnetwork/node/eventbus"
 	"github.com/mysteriumnetwork/node/session/pingpong"
 	"github.com/mysteriumnetwork/node/tequilapi/contract"
+	"github.com/mysteriumnetwork/node/tequilapi/utils"
 )
 
 // EventType represents all the event types we're subscribing to
@@ -153,6 +154,19 @@ func (h *Handler) Sub(c *gin.Context) {
 	}
 }
 
+// Snapshot returns current node state
+// swagger:operation GET /state State stateSnapshot
+//
+//	---
+//	summary: Returns current node state
+//	description: Returns the same state which is sent to /events/state subscribers, e.g. to attach to bug reports. It contains no keys or other secrets.
+//	responses:
+//	  200:
+//	    description: Current node state
+func (h *Handler) Snapshot(c *gin.Context) {
+	utils.WriteAsJSON(mapState(h.stateProvider.GetState()), c.Writer)
+}
+
 func (h *Handler) sendInitialState(messageChan chan string) error {
 	res, err := json.Marshal(Event{
 		Type:    StateChangeEvent,
@@ -286,7 +300,7 @@ func mapState(state stateEvent.State) stateRes {
 		Sessions:      sessionsRes,
 		SessionsStats: contract.NewSessionStatsDTO(sessionsStats),
 		Consumer: consumerStateRes{
-			Connection: contract.NewConnectionDTO(conn.Session, conn.Statistics, conn.Throughput, conn.Invoice),
+			Connection: contract.NewConnectionDTO(conn.Session, conn.Statistics, conn.Throughput, conn.Invoice, conn.Latency),
 		},
 		Identities: identitiesRes,
 		Channels:   channelsRes,
@@ -24,6 +24,7 @@ import (
 	"math/big"
 	"net"
 	"net/http"
+	"net/http/httptest"
 	"strings"
 	"testing"
 	"time"
@@ -289,3 +290,35 @@ func TestHandler_SendsInitialAndFollowingStates(t *testing.T) {
 
 	<-serveExit
 }
+
+func TestHandler_Snapshot(t *testing.T) {
+	msp := &mockStateProvider{stateToReturn: stateEvent.State{Connections: make(map[string]stateEvent.Connection)}}
+	router := summonTestGin()
+	router.GET("/state", NewSSEHandler(msp).Snapshot)
+
+	req, err := http.NewRequest(http.MethodGet, "/state", nil)
+	assert.NoError(t, err)
+	resp := httptest.NewRecorder()
+	router.ServeHTTP(resp, req)
+
+	assert.Equal(t, http.StatusOK, resp.Code)
+	assert.JSONEq(t, `{
+		"service_info": null,
+		"sessions": [],
+		"sessions_stats": {
+			"count": 0,
+			"count_consumers": 0,
+			"sum_bytes_received": 0,
+			"sum_bytes_sent": 0,
+			"sum_duration": 0,
+			"sum_tokens": 0
+		},
+		"consumer": {
+			"connection": {
+				"status": "NotConnected"
+			}
+		},
+		"identities": [],
+		"channels": []
+	}`, resp.Body.String())
+}
@@ -35,13 +35,62 @@ import (
 	"github.com/rs/zerolog/log"
 )
 
-var corsConfig = cors.Config{
-	MaxAge: 30 * 24 * time.Hour,
-	AllowOriginFunc: func(_ string) bool {
-		return false
-	},
+// newCorsConfig returns CORS policy allowing cross-origin requests only from given origins.
+// With no origins given only same origin requests are allowed. Credentials are only allowed if asked for.
+func newCorsConfig(allowedOrigins, allowedMethods string, allowCredentials bool) cors.Config {
+	corsConfig := cors.Config{
+		MaxAge:           30 * 24 * time.Hour,
+		AllowMethods:     splitList(allowedMethods),
+		AllowHeaders:     []string{"Origin", "Content-Type", "Authorization"},
+		AllowCredentials: allowCredentials,
+		AllowOriginFunc: func(_ string) bool {
+			return false
+		},
+	}
+
+	for _, origin := range splitList(allowedOrigins) {
+		if !strings.HasPrefix(origin, "http://") && !strings.HasPrefix(origin, "https://") {
+			log.Warn().Msgf("Ignoring CORS origin without http(s) scheme: %s", origin)
+			continue
+		}
+		corsConfig.AllowOrigins = append(corsConfig.AllowOrigins, origin)
+	}
+
+	return corsConfig
 }
 
+func splitList(list string) []string {
+	var res []string
+	for _, item := range strings.Split(list, ",") {
+		if item = strings.TrimSpace(item); item != "" {
+			res = append(res, item)
+		}
+	}
+	return res
+}
+
+const (
+	// APIVersion is the version of API served.
+	APIVersion = "1"
+	// APIVersionHeader is the response header carrying served API version.
+	APIVersionHeader = "X-API-Version"
+
+	apiVersionPrefix = "/v" + APIVersion
+
+	// maxRequestBodySize bounds memory a single request may take.
+	maxRequestBodySize = 1 << 20
+)
+
+var (
+	// requestHeaderTimeout bounds time to receive request headers. Reading the rest of the connection is not bounded
+	// on server level, as it would also cut long-lived SSE streams.
+	requestHeaderTimeout = 30 * time.Second
+	// requestTimeout bounds time to handle a single request, except for the streaming routes.
+	requestTimeout = time.Minute
+	// streamingPaths are long-lived, so not bounded by requestTimeout.
+	streamingPaths = []string{"/events/state"}
+)
+
 // APIServer interface represents control methods for underlying http api server
 type APIServer interface {
 	Wait() error
@@ -70,9 +119,11 @@ func NewServer(
 ) (APIServer, error) {
 	gin.SetMode(modeFromOptions(nodeOptions))
 	g := gin.New()
+	g.Use(middlewares.NewRequestLogger())
+	g.Use(middlewares.NewBodySizeLimiter(maxRequestBodySize))
 	g.Use(middlewares.ApplyCacheConfigMiddleware)
 	g.Use(gin.Recovery())
-	g.Use(cors.New(corsConfig))
+	g.Use(cors.New(newCorsConfig(nodeOptions.TequilapiAllowedOrigins, nodeOptions.TequilapiAllowedMethods, nodeOptions.TequilapiAllowCredentials)))
 	g.Use(middlewares.NewHostFilter())
 	g.Use(apierror.ErrorHandler)
 
@@ -136,11 +187,35 @@ func (server *apiServer) StartServing() {
 }
 
 func (server *apiServer) serve() {
-	server.errorChannel <- http.Serve(server.listener, server.gin)
+	httpServer := &http.Server{
+		Handler:           withVersionPrefix(middlewares.NewRequestTimeout(server.gin, requestTimeout, streamingPaths...)),
+		ReadHeaderTimeout: requestHeaderTimeout,
+	}
+	server.errorChannel <- httpServer.Serve(server.listener)
+}
+
+// withVersionPrefix serves all routes under versioned prefix as well as unprefixed
+// and reports the API version in response header.
+func withVersionPrefix(handler http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set(APIVersionHeader, APIVersion)
+
+		if path := strings.TrimPrefix(r.URL.Path, apiVersionPrefix); path != r.URL.Path && (path == "" || path[0] == '/') {
+			if path == "" {
+				path = "/"
+			}
+			r.URL.Path = path
+			r.URL.RawPath = ""
+		}
+		handler.ServeHTTP(w, r)
+	})
 }
 
 func extractBoundAddress(listener net.Listener) (string, error) {
 	addr := listener.Addr()
+	if addr.Network() == "unix" {
+		return addr.String(), nil
+	}
 	parts := strings.Split(addr.String(), ":")
 	if len(parts) < 2 {
 		return "", errors.New("Unable to locate address: " + addr.String())
@@ -18,15 +18,24 @@
 package tequilapi
 
 import (
+	"bufio"
+	"fmt"
 	"net"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
 	"strings"
 	"testing"
+	"time"
 
 	"github.com/mysteriumnetwork/node/core/node"
 
+	"github.com/gin-contrib/cors"
 	"github.com/gin-gonic/gin"
 
 	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
 )
 
 func TestLocalAPIServerPortIsAsExpected(t *testing.T) {
@@ -55,3 +64,135 @@ func TestStopBeforeStartingListeningDoesNotCausePanic(t *testing.T) {
 	assert.NoError(t, err)
 	server.Stop()
 }
+
+func TestAPIServerServesOnUnixSocket(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "tequilapi.sock")
+	listener, err := NewUnixSocketListener(path)
+	assert.NoError(t, err)
+
+	info, err := os.Stat(path)
+	assert.NoError(t, err)
+	assert.Equal(t, os.FileMode(0600), info.Mode().Perm())
+
+	server, err := NewServer(listener, *node.GetOptions(), nil, []func(e *gin.Engine) error{})
+	assert.NoError(t, err)
+
+	server.StartServing()
+
+	address, err := server.Address()
+	assert.NoError(t, err)
+	assert.Equal(t, path, address)
+
+	server.Stop()
+	server.Wait()
+}
+
+func TestAPIServerKeepsStreamsOpenPastTimeouts(t *testing.T) {
+	headerTimeout, timeout := requestHeaderTimeout, requestTimeout
+	requestHeaderTimeout, requestTimeout = 50*time.Millisecond, 50*time.Millisecond
+	t.Cleanup(func() {
+		requestHeaderTimeout, requestTimeout = headerTimeout, timeout
+	})
+
+	listener, err := net.Listen("tcp", "127.0.0.1:0")
+	require.NoError(t, err)
+
+	options := *node.GetOptions()
+	options.TequilapiSecured = false
+	server, err := NewServer(listener, options, nil, []func(e *gin.Engine) error{
+		func(e *gin.Engine) error {
+			e.GET("/events/state", func(c *gin.Context) {
+				c.Header("Content-Type", "text/event-stream")
+				ticker := time.NewTicker(10 * time.Millisecond)
+				defer ticker.Stop()
+				for {
+					select {
+					case <-c.Request.Context().Done():
+						return
+					case <-ticker.C:
+						fmt.Fprint(c.Writer, "data: tick\n\n")
+						c.Writer.Flush()
+					}
+				}
+			})
+			return nil
+		},
+	})
+	require.NoError(t, err)
+	server.StartServing()
+	defer server.Stop()
+
+	resp, err := http.Get("http://" + listener.Addr().String() + "/events/state")
+	require.NoError(t, err)
+	defer resp.Body.Close()
+	require.Equal(t, http.StatusOK, resp.StatusCode)
+
+	reader := bufio.NewReader(resp.Body)
+	until := time.Now().Add(4 * requestTimeout)
+	for time.Now().Before(until) {
+		_, err := reader.ReadString('\n')
+		require.NoError(t, err, "stream was closed before the client left")
+	}
+}
+
+func TestCorsAllowsOnlyConfiguredOrigins(t *testing.T) {
+	g := gin.New()
+	g.Use(cors.New(newCorsConfig("https://dashboard.example.com, dashboard.example.com", "GET, POST", false)))
+	g.GET("/healthcheck", func(c *gin.Context) {
+		c.Status(http.StatusOK)
+	})
+
+	preflight := func(origin string) *httptest.ResponseRecorder {
+		req := httptest.NewRequest(http.MethodOptions, "/healthcheck", nil)
+		req.Header.Set("Origin", origin)
+		req.Header.Set("Access-Control-Request-Method", http.MethodGet)
+		resp := httptest.NewRecorder()
+		g.ServeHTTP(resp, req)
+		return resp
+	}
+
+	resp := preflight("https://dashboard.example.com")
+	assert.Equal(t, http.StatusNoContent, resp.Code)
+	assert.Equal(t, "https://dashboard.example.com", resp.Header().Get("Access-Control-Allow-Origin"))
+	assert.Equal(t, "GET,POST", resp.Header().Get("Access-Control-Allow-Methods"))
+	assert.Empty(t, resp.Header().Get("Access-Control-Allow-Credentials"))
+
+	resp = preflight("https://evil.example.com")
+	assert.Equal(t, http.StatusForbidden, resp.Code)
+	assert.Empty(t, resp.Header().Get("Access-Control-Allow-Origin"))
+}
+
+func TestCorsAllowsCredentialsOnlyIfEnabled(t *testing.T) {
+	g := gin.New()
+	g.Use(cors.New(newCorsConfig("https://dashboard.example.com", "GET", true)))
+	g.GET("/healthcheck", func(c *gin.Context) {
+		c.Status(http.StatusOK)
+	})
+
+	req := httptest.NewRequest(http.MethodGet, "/healthcheck", nil)
+	req.Header.Set("Origin", "https://dashboard.example.com")
+	resp := httptest.NewRecorder()
+	g.ServeHTTP(resp, req)
+
+	assert.Equal(t, http.StatusOK, resp.Code)
+	assert.Equal(t, "true", resp.Header().Get("Access-Control-Allow-Credentials"))
+}
+
+func TestVersionPrefixIsServedAlongsideUnprefixedRoutes(t *testing.T) {
+	g := gin.New()
+	g.GET("/healthcheck", func(c *gin.Context) {
+		c.Status(http.StatusOK)
+	})
+	handler := withVersionPrefix(g)
+
+	for _, path := range []string{"/healthcheck", "/v1/healthcheck"} {
+		resp := httptest.NewRecorder()
+		handler.ServeHTTP(resp, httptest.NewRequest(http.MethodGet, path, nil))
+		assert.Equal(t, http.StatusOK, resp.Code, path)
+		assert.Equal(t, APIVersion, resp.Header().Get(APIVersionHeader), path)
+	}
+
+	resp := httptest.NewRecorder()
+	handler.ServeHTTP(resp, httptest.NewRequest(http.MethodGet, "/v1healthcheck", nil))
+	assert.Equal(t, http.StatusNotFound, resp.Code)
+}
@@ -17,13 +17,36 @@
 
 package tequilapi
 
-import "net"
+import (
+	"errors"
+	"fmt"
+	"io/fs"
+	"net"
+	"os"
+)
 
 // NewListener returns tequilapi listener.
 func NewListener(network, address string) (net.Listener, error) {
 	return net.Listen(network, address)
 }
 
+// NewUnixSocketListener returns tequilapi listener on unix socket accessible only by the current user.
+func NewUnixSocketListener(path string) (net.Listener, error) {
+	if err := os.Remove(path); err != nil && !errors.Is(err, fs.ErrNotExist) {
+		return nil, fmt.Errorf("could not remove stale socket %s: %w", path, err)
+	}
+
+	listener, err := net.Listen("unix", path)
+	if err != nil {
+		return nil, err
+	}
+	if err := os.Chmod(path, 0600); err != nil {
+		listener.Close()
+		return nil, fmt.Errorf("could not restrict socket permissions: %w", err)
+	}
+	return listener, nil
+}
+
 // NewNoopListener returns noop tequilapi listener.
 func NewNoopListener() (net.Listener, error) {
 	return &noopListener{}, nil
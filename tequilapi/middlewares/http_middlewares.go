@@ -21,8 +21,13 @@ import (
 	"net"
 	"net/http"
 	"strings"
+	"sync"
+	"time"
 
 	"github.com/gin-gonic/gin"
+	"github.com/gofrs/uuid"
+	"github.com/rs/zerolog/log"
+	"golang.org/x/time/rate"
 
 	"github.com/mysteriumnetwork/node/config"
 	"github.com/mysteriumnetwork/node/utils/domain"
@@ -33,6 +38,68 @@ func ApplyCacheConfigMiddleware(ctx *gin.Context) {
 	ctx.Writer.Header().Set("Cache-control", strings.Join([]string{"no-cache", "no-store", "must-revalidate"}, ", "))
 }
 
+// RequestIDHeader is the header carrying request ID, which is either passed by the client or generated.
+const RequestIDHeader = "X-Request-ID"
+
+// NewRequestLogger returns instance of middleware assigning request ID to every
+// request and logging the request once it is handled
+func NewRequestLogger() func(*gin.Context) {
+	return func(c *gin.Context) {
+		requestID := c.GetHeader(RequestIDHeader)
+		if requestID == "" {
+			uid, err := uuid.NewV4()
+			if err == nil {
+				requestID = uid.String()
+			}
+		}
+		c.Header(RequestIDHeader, requestID)
+
+		start := time.Now()
+		c.Next()
+
+		log.Debug().
+			Str("request_id", requestID).
+			Str("method", c.Request.Method).
+			Str("path", c.Request.URL.Path).
+			Int("status", c.Writer.Status()).
+			Dur("latency", time.Since(start)).
+			Msg("Handled API request")
+	}
+}
+
+// requestTimeoutBody is returned with 503 Service Unavailable to requests not handled in time.
+const requestTimeoutBody = `{"error":{"code":"request_timeout","message":"Request was not handled in time"},"status":503}`
+
+// NewRequestTimeout wraps given handler to respond with 503 Service Unavailable to requests not handled
+// within given timeout, the same way http.TimeoutHandler does. Request context is cancelled on timeout too.
+// Requests to the given paths serving long-lived streams are not bounded.
+func NewRequestTimeout(handler http.Handler, timeout time.Duration, streamingPaths ...string) http.Handler {
+	timeoutHandler := http.TimeoutHandler(handler, timeout, requestTimeoutBody)
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		for _, path := range streamingPaths {
+			if r.URL.Path == path {
+				handler.ServeHTTP(w, r)
+				return
+			}
+		}
+		timeoutHandler.ServeHTTP(w, r)
+	})
+}
+
+// NewBodySizeLimiter returns instance of middleware rejecting requests
+// with body larger than given size
+func NewBodySizeLimiter(maxBytes int64) func(*gin.Context) {
+	return func(c *gin.Context) {
+		if c.Request.ContentLength > maxBytes {
+			c.AbortWithStatus(http.StatusRequestEntityTooLarge)
+			return
+		}
+		if c.Request.Body != nil {
+			c.Request.Body = http.MaxBytesReader(c.Writer, c.Request.Body, maxBytes)
+		}
+	}
+}
+
 // NewHostFilter returns instance of middleware allowing only requests
 // with allowed domains in Host header
 func NewHostFilter() func(*gin.Context) {
@@ -63,9 +130,13 @@ func NewHostFilter() func(*gin.Context) {
 }
 
 // NewLocalhostOnlyFilter returns instance of middleware allowing only requests
-// with local client IP.
+// with local client IP or received on unix socket.
 func NewLocalhostOnlyFilter() func(*gin.Context) {
 	return func(c *gin.Context) {
+		// Unix socket peers are local by definition, they have no IP though.
+		if addr, ok := c.Request.Context().Value(http.LocalAddrContextKey).(net.Addr); ok && addr.Network() == "unix" {
+			return
+		}
 
 		// ClientIP() parses the headers defined in Engine.RemoteIPHeaders if there is
 		// so it handles clients behind proxy
@@ -77,3 +148,72 @@ func NewLocalhostOnlyFilter() func(*gin.Context) {
 		c.AbortWithStatus(http.StatusForbidden)
 	}
 }
+
+// NewRateLimiter returns instance of middleware allowing a client IP to make a request
+// once per given interval, with bursts of up to given size. Clients are told apart by the peer address,
+// as forwarding headers are set by the client itself and would give it a fresh limit on every request.
+func NewRateLimiter(interval time.Duration, burst int) func(*gin.Context) {
+	limiter := newRateLimiter(interval, burst)
+
+	return func(c *gin.Context) {
+		if !limiter.allow(c.RemoteIP()) {
+			c.AbortWithStatus(http.StatusTooManyRequests)
+		}
+	}
+}
+
+type rateLimitedClient struct {
+	limiter  *rate.Limiter
+	lastSeen time.Time
+}
+
+type rateLimiter struct {
+	interval time.Duration
+	burst    int
+	// idleTimeout is the time it takes for an idle client's limiter to refill completely,
+	// it does not differ from a new one after that and can be dropped.
+	idleTimeout time.Duration
+	now         func() time.Time
+
+	mu        sync.Mutex
+	clients   map[string]*rateLimitedClient
+	lastSweep time.Time
+}
+
+func newRateLimiter(interval time.Duration, burst int) *rateLimiter {
+	return &rateLimiter{
+		interval:    interval,
+		burst:       burst,
+		idleTimeout: interval * time.Duration(burst),
+		now:         time.Now,
+		clients:     make(map[string]*rateLimitedClient),
+	}
+}
+
+func (rl *rateLimiter) allow(clientIP string) bool {
+	rl.mu.Lock()
+	defer rl.mu.Unlock()
+
+	now := rl.now()
+	if now.Sub(rl.lastSweep) >= rl.idleTimeout {
+		rl.evictIdle(now)
+	}
+
+	client, ok := rl.clients[clientIP]
+	if !ok {
+		client = &rateLimitedClient{limiter: rate.NewLimiter(rate.Every(rl.interval), rl.burst)}
+		rl.clients[clientIP] = client
+	}
+	client.lastSeen = now
+
+	return client.limiter.AllowN(now, 1)
+}
+
+func (rl *rateLimiter) evictIdle(now time.Time) {
+	for ip, client := range rl.clients {
+		if now.Sub(client.lastSeen) >= rl.idleTimeout {
+			delete(rl.clients, ip)
+		}
+	}
+	rl.lastSweep = now
+}
@@ -18,9 +18,15 @@
 package middlewares
 
 import (
+	"context"
+	"fmt"
+	"io"
+	"net"
 	"net/http"
 	"net/http/httptest"
+	"strings"
 	"testing"
+	"time"
 
 	"github.com/gin-gonic/gin"
 
@@ -43,3 +49,168 @@ func TestCacheControlHeadersAreAddedToResponse(t *testing.T) {
 	)
 
 }
+
+func TestRequestLoggerAssignsRequestID(t *testing.T) {
+	g := gin.New()
+	g.Use(NewRequestLogger())
+	g.GET("/healthcheck", func(c *gin.Context) {
+		c.Status(http.StatusOK)
+	})
+
+	req := httptest.NewRequest(http.MethodGet, "/healthcheck", nil)
+	resp := httptest.NewRecorder()
+	g.ServeHTTP(resp, req)
+	assert.NotEmpty(t, resp.Header().Get(RequestIDHeader))
+
+	req = httptest.NewRequest(http.MethodGet, "/healthcheck", nil)
+	req.Header.Set(RequestIDHeader, "client-request-1")
+	resp = httptest.NewRecorder()
+	g.ServeHTTP(resp, req)
+	assert.Equal(t, "client-request-1", resp.Header().Get(RequestIDHeader))
+}
+
+func TestRateLimiterRejectsRequestsOverLimit(t *testing.T) {
+	g := gin.New()
+	g.POST("/identities-import", NewRateLimiter(time.Hour, 2), func(c *gin.Context) {
+		c.Status(http.StatusOK)
+	})
+
+	statuses := make([]int, 3)
+	for i := range statuses {
+		req := httptest.NewRequest(http.MethodPost, "/identities-import", nil)
+		resp := httptest.NewRecorder()
+		g.ServeHTTP(resp, req)
+		statuses[i] = resp.Code
+	}
+
+	assert.Equal(t, []int{http.StatusOK, http.StatusOK, http.StatusTooManyRequests}, statuses)
+}
+
+func TestRateLimiterIgnoresForwardedFor(t *testing.T) {
+	g := gin.New()
+	g.SetTrustedProxies([]string{"127.0.0.1"})
+	g.POST("/identities-import", NewRateLimiter(time.Hour, 2), func(c *gin.Context) {
+		c.Status(http.StatusOK)
+	})
+
+	statuses := make([]int, 3)
+	for i := range statuses {
+		req := httptest.NewRequest(http.MethodPost, "/identities-import", nil)
+		req.RemoteAddr = "127.0.0.1:51000"
+		req.Header.Set("X-Forwarded-For", fmt.Sprintf("10.0.0.%d", i+1))
+		resp := httptest.NewRecorder()
+		g.ServeHTTP(resp, req)
+		statuses[i] = resp.Code
+	}
+
+	assert.Equal(t, []int{http.StatusOK, http.StatusOK, http.StatusTooManyRequests}, statuses)
+}
+
+func TestRateLimiterEvictsOnlyIdleClients(t *testing.T) {
+	now := time.Now()
+	limiter := newRateLimiter(time.Second, 1)
+	limiter.now = func() time.Time { return now }
+
+	assert.True(t, limiter.allow("10.0.0.1"))
+	now = now.Add(500 * time.Millisecond)
+	assert.True(t, limiter.allow("10.0.0.2"))
+
+	now = now.Add(500 * time.Millisecond)
+	assert.False(t, limiter.allow("10.0.0.2"), "active client must keep its limit")
+	assert.NotContains(t, limiter.clients, "10.0.0.1")
+	assert.Contains(t, limiter.clients, "10.0.0.2")
+}
+
+func TestRequestTimeoutCutsOffOnlyNonStreamingRequests(t *testing.T) {
+	g := gin.New()
+	// Slow handler ignores request context, so only the timeout handler can cut it off.
+	handler := func(c *gin.Context) {
+		time.Sleep(time.Second)
+		c.Status(http.StatusOK)
+	}
+	g.GET("/identities", handler)
+	g.GET("/events/state", handler)
+	server := NewRequestTimeout(g, 10*time.Millisecond, "/events/state")
+
+	started := time.Now()
+	resp := httptest.NewRecorder()
+	server.ServeHTTP(resp, httptest.NewRequest(http.MethodGet, "/identities", nil))
+	assert.Equal(t, http.StatusServiceUnavailable, resp.Code)
+	assert.Contains(t, resp.Body.String(), "request_timeout")
+	assert.Less(t, time.Since(started), 500*time.Millisecond)
+
+	resp = httptest.NewRecorder()
+	server.ServeHTTP(resp, httptest.NewRequest(http.MethodGet, "/events/state", nil))
+	assert.Equal(t, http.StatusOK, resp.Code)
+}
+
+func TestBodySizeLimiterRejectsLargeBodies(t *testing.T) {
+	g := gin.New()
+	g.Use(NewBodySizeLimiter(8))
+	g.POST("/identities-import", func(c *gin.Context) {
+		if _, err := io.ReadAll(c.Request.Body); err != nil {
+			c.Status(http.StatusRequestEntityTooLarge)
+			return
+		}
+		c.Status(http.StatusOK)
+	})
+
+	resp := httptest.NewRecorder()
+	g.ServeHTTP(resp, httptest.NewRequest(http.MethodPost, "/identities-import", strings.NewReader("small")))
+	assert.Equal(t, http.StatusOK, resp.Code)
+
+	resp = httptest.NewRecorder()
+	g.ServeHTTP(resp, httptest.NewRequest(http.MethodPost, "/identities-import", strings.NewReader("too large body")))
+	assert.Equal(t, http.StatusRequestEntityTooLarge, resp.Code)
+
+	// Body of unknown length is cut off while reading.
+	req := httptest.NewRequest(http.MethodPost, "/identities-import", io.NopCloser(strings.NewReader("too large body")))
+	req.ContentLength = -1
+	resp = httptest.NewRecorder()
+	g.ServeHTTP(resp, req)
+	assert.Equal(t, http.StatusRequestEntityTooLarge, resp.Code)
+}
+
+func TestLocalhostOnlyFilter(t *testing.T) {
+	tests := []struct {
+		name       string
+		remoteAddr string
+		localAddr  net.Addr
+		wantStatus int
+	}{
+		{
+			name:       "loopback client is allowed",
+			remoteAddr: "127.0.0.1:51000",
+			localAddr:  &net.TCPAddr{IP: net.IPv4(127, 0, 0, 1), Port: 4050},
+			wantStatus: http.StatusOK,
+		},
+		{
+			name:       "remote client is forbidden",
+			remoteAddr: "192.168.1.10:51000",
+			localAddr:  &net.TCPAddr{IP: net.IPv4(192, 168, 1, 1), Port: 4050},
+			wantStatus: http.StatusForbidden,
+		},
+		{
+			name:       "unix socket client is allowed",
+			remoteAddr: "@",
+			localAddr:  &net.UnixAddr{Name: "/run/myst/tequilapi.sock", Net: "unix"},
+			wantStatus: http.StatusOK,
+		},
+	}
+	for _, test := range tests {
+		t.Run(test.name, func(t *testing.T) {
+			g := gin.New()
+			g.GET("/local", NewLocalhostOnlyFilter(), func(c *gin.Context) {
+				c.Status(http.StatusOK)
+			})
+
+			req := httptest.NewRequest(http.MethodGet, "/local", nil)
+			req.RemoteAddr = test.remoteAddr
+			req = req.WithContext(context.WithValue(req.Context(), http.LocalAddrContextKey, test.localAddr))
+			resp := httptest.NewRecorder()
+			g.ServeHTTP(resp, req)
+
+			assert.Equal(t, test.wantStatus, resp.Code)
+		})
+	}
+}